@@ -1,11 +1,14 @@
+use monetix_connector::rest::currencies::Currency;
 use monetix_connector::rest::gate::models::MonetixPaymentModel;
 use monetix_connector::rest::gate::payout::{
     MonetixCardModel, MonetixCustomerAccountModel, MonetixCustomerIdentifyModel,
     MonetixCustomerPayoutModel, MonetixPayoutPaymentModel,
 };
+use monetix_connector::rest::gate::payout_method::PayoutMethod;
 use monetix_connector::rest::gate::rest_client::MonetixGateRestClient;
 use monetix_connector::rest::healthcheck::models::GetPaymentPageArgs;
 use monetix_connector::rest::healthcheck::rest_client::MonetixHealthcheckRestClient;
+use monetix_connector::rest::money::Money;
 use monetix_connector::rest::payment_page::PaymentPage;
 use uuid::Uuid;
 
@@ -32,16 +35,14 @@ pub async fn make_payout() {
         number: "4000000000000077".to_string(),
     };
     let payment = MonetixPayoutPaymentModel {
-        amount: 1000000,
-        currency: "MXN".to_string(),
+        money: Money::from_minor(1000000, Currency::MXN),
     };
 
-    let payment_method = "card"; // card, spei, nequi (has other endpoint), colombia
 
     let result = gate_client
         .make_payout(
             generate_payment_id(),
-            payment_method,
+            PayoutMethod::Card,
             customer,
             account,
             payment,
@@ -67,7 +68,7 @@ pub async fn payment_page() {
     let payment_args = GetPaymentPageArgs {
         payment_id: generate_payment_id(),
         payment_amount: 10,
-        payment_currency: "USD".to_string(),
+        payment_currency: Currency::USD,
         project_id,
         customer_id: "test".to_string(),
         customer_first_name: "test".to_string(),
@@ -107,8 +108,7 @@ pub async fn create_invoice() {
             format!("test-{}", Uuid::new_v4()),
             "test-customer-id-1",
             MonetixPaymentModel {
-                amount: 5,
-                currency: "USD".to_string(),
+                money: Money::from_minor(5, Currency::USD),
                 description: Some("test".into()),
                 extra_param: Some("test".into()),
                 best_before: "2050-01-01T00:00:00+00:00".to_string(),
@@ -1,4 +1,6 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use crate::rest::status::{OperationStatus, OperationType, PaymentStatus, PaymentType};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixCallbackModel {
@@ -23,17 +25,85 @@ pub struct MonetixCallbackModel {
     pub decision: Option<String>,
     /// The object that contains the data from the payment provider that are required to display
     /// QR code with payment details to the customer.
-    pub display_data: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_display_data")]
+    pub display_data: Vec<MonetixDisplayDataModel>,
     pub errors: Option<Vec<MonetixErrorItemModel>>,
     pub interface_type: Option<MonetixInterfaceTypeModel>,
     /// The object that contains information about the operation that triggered the callback
     pub operation: Option<MonetixOperationModel>,
     pub payment: MonetixCallbackPaymentModel,
     pub project_id: u64,
+    /// The object that contains the recurring registered with the payment.
+    pub recurring: Option<MonetixCallbackRecurringModel>,
     pub redirect_data: Option<MonetixRedirectDataModel>,
     pub signature: String,
 }
 
+/// What the customer has to do to pass 3‑D Secure.
+#[derive(Debug, Clone)]
+pub enum MonetixThreeDsAction {
+    /// 3‑D Secure 1: post `pa_req` and `md` to `acs_url`, then pass the returned PaRes to
+    /// `MonetixGateRestClient::complete_3ds`.
+    Acs(MonetixAcsModel),
+    /// 3‑D Secure 2 or APM: redirect the customer with the given method, url and body.
+    Redirect(MonetixRedirectDataModel),
+}
+
+impl MonetixCallbackModel {
+    pub fn three_ds_action(&self) -> Option<MonetixThreeDsAction> {
+        if let Some(acs) = &self.acs {
+            return Some(MonetixThreeDsAction::Acs(acs.clone()));
+        }
+
+        if let Some(redirect_data) = &self.redirect_data {
+            if redirect_data.url.is_some() {
+                return Some(MonetixThreeDsAction::Redirect(redirect_data.clone()));
+            }
+        }
+
+        None
+    }
+
+    /// Value of the first `display_data` item of the given type.
+    pub fn display_data(&self, data_type: &str) -> Option<&str> {
+        self.display_data
+            .iter()
+            .find(|item| item.data_type == data_type)
+            .map(|item| item.data.as_str())
+    }
+
+    /// PIX copy-and-paste code, the customer pays by scanning it as a QR code or pasting it in the bank app.
+    pub fn pix_qr_payload(&self) -> Option<&str> {
+        self.display_data("qr_data")
+    }
+}
+
+/// Item of the data to display to the customer, e.g. a QR code payload.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixDisplayDataModel {
+    #[serde(rename = "type")]
+    pub data_type: String,
+    pub title: Option<String>,
+    pub data: String,
+}
+
+/// Accepts the array of items or the same array encoded in a string. Malformed items are skipped
+/// and anything else gives an empty list, so that an odd `display_data` doesn't fail the whole callback.
+fn deserialize_display_data<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<MonetixDisplayDataModel>, D::Error> {
+    let value = match Value::deserialize(deserializer)? {
+        Value::String(json) => serde_json::from_str(&json).unwrap_or(Value::Null),
+        value => value,
+    };
+
+    let Value::Array(items) = value else {
+        return Ok(Vec::new());
+    };
+
+    Ok(items.into_iter().filter_map(|item| serde_json::from_value(item).ok()).collect())
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixOperationModel {
     pub code: Option<String>,
@@ -47,11 +117,11 @@ pub struct MonetixOperationModel {
     pub message: Option<String>,
     pub provider: Option<MonetixOperationProviderModel>,
     pub request_id: String,
-    pub status: String,
+    pub status: OperationStatus,
     pub sum_converted: Option<MonetixSumModel>,
     pub sum_initial: Option<MonetixSumModel>,
     #[serde(rename = "type")]
-    pub op_type: String,
+    pub op_type: OperationType,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -142,18 +212,84 @@ pub struct MonetixErrorItemModel {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixCallbackPaymentModel {
     pub id: String,
-    pub status: String,
+    pub status: PaymentStatus,
     #[serde(rename = "type")]
-    pub payment_type: String,
+    pub payment_type: PaymentType,
     pub sum: MonetixSumModel,
     pub description: Option<String>,
     pub method: Option<String>,
     pub date: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixCallbackRecurringModel {
+    /// ID of the recurring to pass as `recurring_id` in the following charges.
+    pub id: Option<u64>,
+    pub currency: Option<String>,
+    pub status: Option<String>,
+    #[serde(rename = "type")]
+    pub r_type: Option<String>,
+    /// Date the recurring expires in MM-YYYY format.
+    pub valid_thru: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixRedirectDataModel {
     pub body: Option<String>,
     pub method: Option<String>,
     pub url: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::callback::{MonetixCallbackModel, MonetixThreeDsAction};
+
+    fn callback(extra: &str) -> MonetixCallbackModel {
+        let json = format!(
+            r#"{{"customer": {{"id": "c"}}, "payment": {{"id": "p", "status": "awaiting 3ds result", "type": "purchase", "sum": {{}}}}, "project_id": 1, "signature": ""{extra}}}"#
+        );
+
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn acs() {
+        let callback = callback(r#", "acs": {"acs_url": "https://acs", "md": "md", "pa_req": "pa_req"}"#);
+
+        let Some(MonetixThreeDsAction::Acs(acs)) = callback.three_ds_action() else {
+            panic!("expected acs");
+        };
+        assert_eq!(acs.acs_url, "https://acs");
+        assert_eq!(acs.md, "md");
+        assert_eq!(acs.pa_req, "pa_req");
+    }
+
+    #[test]
+    fn redirect() {
+        let callback = callback(r#", "redirect_data": {"method": "POST", "url": "https://3ds", "body": "{}"}"#);
+
+        let Some(MonetixThreeDsAction::Redirect(redirect_data)) = callback.three_ds_action() else {
+            panic!("expected redirect");
+        };
+        assert_eq!(redirect_data.url.as_deref(), Some("https://3ds"));
+        assert_eq!(redirect_data.method.as_deref(), Some("POST"));
+    }
+
+    #[test]
+    fn redirect_without_url() {
+        let without_url = callback(r#", "redirect_data": {"method": "POST", "body": "{}"}"#);
+
+        assert!(without_url.three_ds_action().is_none());
+        assert!(callback("").three_ds_action().is_none());
+    }
+
+    #[test]
+    fn malformed_display_data() {
+        let garbage = callback(r#", "display_data": "not json""#);
+        let mixed = callback(r#", "display_data": [{"type": "qr_data"}, {"type": "qr_data", "data": "000201"}]"#);
+
+        assert!(garbage.display_data.is_empty());
+        assert_eq!(callback(r#", "display_data": 42"#).pix_qr_payload(), None);
+        assert_eq!(mixed.pix_qr_payload(), Some("000201"));
+    }
+}
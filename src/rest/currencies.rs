@@ -1,177 +1,301 @@
-use lazy_static::lazy_static;
-use std::collections::HashMap;
-
-lazy_static! {
-    pub static ref DIGITS_BY_CODES: HashMap<String, u32> = {
-        let mut map = HashMap::with_capacity(200);
-        map.insert("AED".to_string(), 2);
-        map.insert("AFN".to_string(), 2);
-        map.insert("ALL".to_string(), 2);
-        map.insert("AMD".to_string(), 2);
-        map.insert("ANG".to_string(), 2);
-        map.insert("AOA".to_string(), 2);
-        map.insert("ARS".to_string(), 2);
-        map.insert("AUD".to_string(), 2);
-        map.insert("AWG".to_string(), 2);
-        map.insert("AZN".to_string(), 2);
-        map.insert("BAM".to_string(), 2);
-        map.insert("BBD".to_string(), 2);
-        map.insert("BDT".to_string(), 2);
-        map.insert("BGN".to_string(), 2);
-        map.insert("BHD".to_string(), 3);
-        map.insert("BIF".to_string(), 0);
-        map.insert("BMD".to_string(), 2);
-        map.insert("BND".to_string(), 2);
-        map.insert("BOB".to_string(), 2);
-        map.insert("BOV".to_string(), 2);
-        map.insert("BRL".to_string(), 2);
-        map.insert("BSD".to_string(), 2);
-        map.insert("BTN".to_string(), 2);
-        map.insert("BWP".to_string(), 2);
-        map.insert("BYN".to_string(), 2);
-        map.insert("BZD".to_string(), 2);
-        map.insert("CAD".to_string(), 2);
-        map.insert("CDF".to_string(), 2);
-        map.insert("CHE".to_string(), 2);
-        map.insert("CHF".to_string(), 2);
-        map.insert("CHW".to_string(), 2);
-        map.insert("CLF".to_string(), 4);
-        map.insert("CLP".to_string(), 0);
-        map.insert("CNY".to_string(), 2);
-        map.insert("COP".to_string(), 2);
-        map.insert("COU".to_string(), 2);
-        map.insert("CRC".to_string(), 2);
-        map.insert("CUC".to_string(), 2);
-        map.insert("CUP".to_string(), 2);
-        map.insert("CVE".to_string(), 0);
-        map.insert("CZK".to_string(), 2);
-        map.insert("DJF".to_string(), 0);
-        map.insert("DKK".to_string(), 2);
-        map.insert("DOP".to_string(), 2);
-        map.insert("DZD".to_string(), 2);
-        map.insert("EGP".to_string(), 2);
-        map.insert("ERN".to_string(), 2);
-        map.insert("ETB".to_string(), 2);
-        map.insert("EUR".to_string(), 2);
-        map.insert("FJD".to_string(), 2);
-        map.insert("FKP".to_string(), 2);
-        map.insert("GBP".to_string(), 2);
-        map.insert("GEL".to_string(), 2);
-        map.insert("GHS".to_string(), 2);
-        map.insert("GIP".to_string(), 2);
-        map.insert("GMD".to_string(), 2);
-        map.insert("GNF".to_string(), 0);
-        map.insert("GTQ".to_string(), 2);
-        map.insert("GYD".to_string(), 2);
-        map.insert("HKD".to_string(), 2);
-        map.insert("HNL".to_string(), 2);
-        map.insert("HRK".to_string(), 2);
-        map.insert("HTG".to_string(), 2);
-        map.insert("HUF".to_string(), 2);
-        map.insert("IDR".to_string(), 2);
-        map.insert("ILS".to_string(), 2);
-        map.insert("INR".to_string(), 2);
-        map.insert("IQD".to_string(), 3);
-        map.insert("IRR".to_string(), 2);
-        map.insert("ISK".to_string(), 0);
-        map.insert("JMD".to_string(), 2);
-        map.insert("JOD".to_string(), 3);
-        map.insert("JPY".to_string(), 0);
-        map.insert("KES".to_string(), 2);
-        map.insert("KGS".to_string(), 2);
-        map.insert("KHR".to_string(), 2);
-        map.insert("KMF".to_string(), 0);
-        map.insert("KPW".to_string(), 2);
-        map.insert("KRW".to_string(), 0);
-        map.insert("KWD".to_string(), 3);
-        map.insert("KYD".to_string(), 2);
-        map.insert("KZT".to_string(), 2);
-        map.insert("LAK".to_string(), 2);
-        map.insert("LBP".to_string(), 2);
-        map.insert("LKR".to_string(), 2);
-        map.insert("LRD".to_string(), 2);
-        map.insert("LSL".to_string(), 2);
-        map.insert("LYD".to_string(), 3);
-        map.insert("MAD".to_string(), 2);
-        map.insert("MDL".to_string(), 2);
-        map.insert("MGA".to_string(), 1);
-        map.insert("MKD".to_string(), 2);
-        map.insert("MMK".to_string(), 2);
-        map.insert("MNT".to_string(), 2);
-        map.insert("MOP".to_string(), 2);
-        map.insert("MRU".to_string(), 1);
-        map.insert("MUR".to_string(), 2);
-        map.insert("MVR".to_string(), 2);
-        map.insert("MWK".to_string(), 2);
-        map.insert("MXN".to_string(), 2);
-        map.insert("MXV".to_string(), 2);
-        map.insert("MYR".to_string(), 2);
-        map.insert("MZN".to_string(), 2);
-        map.insert("NAD".to_string(), 2);
-        map.insert("NGN".to_string(), 2);
-        map.insert("NIO".to_string(), 2);
-        map.insert("NOK".to_string(), 2);
-        map.insert("NPR".to_string(), 2);
-        map.insert("NZD".to_string(), 2);
-        map.insert("OMR".to_string(), 3);
-        map.insert("PAB".to_string(), 2);
-        map.insert("PEN".to_string(), 2);
-        map.insert("PGK".to_string(), 2);
-        map.insert("PHP".to_string(), 2);
-        map.insert("PKR".to_string(), 2);
-        map.insert("PLN".to_string(), 2);
-        map.insert("PYG".to_string(), 0);
-        map.insert("QAR".to_string(), 2);
-        map.insert("RON".to_string(), 2);
-        map.insert("RSD".to_string(), 2);
-        map.insert("RUB".to_string(), 2);
-        map.insert("RWF".to_string(), 0);
-        map.insert("SAR".to_string(), 2);
-        map.insert("SBD".to_string(), 2);
-        map.insert("SCR".to_string(), 2);
-        map.insert("SDG".to_string(), 2);
-        map.insert("SEK".to_string(), 2);
-        map.insert("SGD".to_string(), 2);
-        map.insert("SHP".to_string(), 2);
-        map.insert("SLL".to_string(), 2);
-        map.insert("SOS".to_string(), 2);
-        map.insert("SRD".to_string(), 2);
-        map.insert("SSP".to_string(), 2);
-        map.insert("STN".to_string(), 2);
-        map.insert("SVC".to_string(), 2);
-        map.insert("SYP".to_string(), 2);
-        map.insert("SZL".to_string(), 2);
-        map.insert("THB".to_string(), 2);
-        map.insert("TJS".to_string(), 2);
-        map.insert("TMT".to_string(), 2);
-        map.insert("TND".to_string(), 3);
-        map.insert("TOP".to_string(), 2);
-        map.insert("TRY".to_string(), 2);
-        map.insert("TTD".to_string(), 2);
-        map.insert("TWD".to_string(), 2);
-        map.insert("TZS".to_string(), 2);
-        map.insert("UAH".to_string(), 2);
-        map.insert("UGX".to_string(), 0);
-        map.insert("USD".to_string(), 2);
-        map.insert("UYI".to_string(), 0);
-        map.insert("UYU".to_string(), 2);
-        map.insert("UYW".to_string(), 4);
-        map.insert("UZS".to_string(), 2);
-        map.insert("VES".to_string(), 2);
-        map.insert("VND".to_string(), 0);
-        map.insert("VUV".to_string(), 0);
-        map.insert("WST".to_string(), 2);
-        map.insert("XAF".to_string(), 0);
-        map.insert("XCD".to_string(), 2);
-        map.insert("XDR".to_string(), 0);
-        map.insert("XOF".to_string(), 0);
-        map.insert("XPF".to_string(), 0);
-        map.insert("XSU".to_string(), 2);
-        map.insert("YER".to_string(), 2);
-        map.insert("ZAR".to_string(), 2);
-        map.insert("ZMW".to_string(), 2);
-        map.insert("ZWL".to_string(), 2);
-
-        map
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+macro_rules! currencies {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $code:literal, $numeric:literal, $digits:literal, $full_name:literal;)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[allow(clippy::upper_case_acronyms)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum $name {
+            $(#[doc = $full_name] $variant,)+
+        }
+
+        impl $name {
+            /// All known currencies in alphabetical order of their codes.
+            pub const VALUES: &'static [$name] = &[$($name::$variant,)+];
+
+            /// Alpha-3 code, e.g. USD
+            pub const fn code(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $code,)+
+                }
+            }
+
+            /// Numeric code, e.g. 840 for USD
+            pub const fn numeric_code(&self) -> u16 {
+                match self {
+                    $($name::$variant => $numeric,)+
+                }
+            }
+
+            /// Number of digits in minor units, e.g. 2 for USD and 0 for JPY
+            pub const fn minor_digits(&self) -> u32 {
+                match self {
+                    $($name::$variant => $digits,)+
+                }
+            }
+
+            /// English name, e.g. US Dollar
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $full_name,)+
+                }
+            }
+
+            /// Returns `None` for unknown codes. The code is case-insensitive.
+            pub fn from_code(code: &str) -> Option<Self> {
+                match code.to_ascii_uppercase().as_str() {
+                    $($code => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+
+            pub const fn from_numeric_code(numeric_code: u16) -> Option<Self> {
+                match numeric_code {
+                    $($numeric => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+        }
     };
 }
+
+currencies! {
+    /// ISO 4217 currency with the minor units used by Monetix.
+    pub enum Currency {
+        AED => "AED", 784, 2, "UAE Dirham";
+        AFN => "AFN", 971, 2, "Afghani";
+        ALL => "ALL", 8, 2, "Lek";
+        AMD => "AMD", 51, 2, "Armenian Dram";
+        ANG => "ANG", 532, 2, "Netherlands Antillean Guilder";
+        AOA => "AOA", 973, 2, "Kwanza";
+        ARS => "ARS", 32, 2, "Argentine Peso";
+        AUD => "AUD", 36, 2, "Australian Dollar";
+        AWG => "AWG", 533, 2, "Aruban Florin";
+        AZN => "AZN", 944, 2, "Azerbaijan Manat";
+        BAM => "BAM", 977, 2, "Convertible Mark";
+        BBD => "BBD", 52, 2, "Barbados Dollar";
+        BDT => "BDT", 50, 2, "Taka";
+        BGN => "BGN", 975, 2, "Bulgarian Lev";
+        BHD => "BHD", 48, 3, "Bahraini Dinar";
+        BIF => "BIF", 108, 0, "Burundi Franc";
+        BMD => "BMD", 60, 2, "Bermudian Dollar";
+        BND => "BND", 96, 2, "Brunei Dollar";
+        BOB => "BOB", 68, 2, "Boliviano";
+        BOV => "BOV", 984, 2, "Mvdol";
+        BRL => "BRL", 986, 2, "Brazilian Real";
+        BSD => "BSD", 44, 2, "Bahamian Dollar";
+        BTN => "BTN", 64, 2, "Ngultrum";
+        BWP => "BWP", 72, 2, "Pula";
+        BYN => "BYN", 933, 2, "Belarusian Ruble";
+        BZD => "BZD", 84, 2, "Belize Dollar";
+        CAD => "CAD", 124, 2, "Canadian Dollar";
+        CDF => "CDF", 976, 2, "Congolese Franc";
+        CHE => "CHE", 947, 2, "WIR Euro";
+        CHF => "CHF", 756, 2, "Swiss Franc";
+        CHW => "CHW", 948, 2, "WIR Franc";
+        CLF => "CLF", 990, 4, "Unidad de Fomento";
+        CLP => "CLP", 152, 0, "Chilean Peso";
+        CNY => "CNY", 156, 2, "Yuan Renminbi";
+        COP => "COP", 170, 2, "Colombian Peso";
+        COU => "COU", 970, 2, "Unidad de Valor Real";
+        CRC => "CRC", 188, 2, "Costa Rican Colon";
+        CUC => "CUC", 931, 2, "Peso Convertible";
+        CUP => "CUP", 192, 2, "Cuban Peso";
+        CVE => "CVE", 132, 0, "Cabo Verde Escudo";
+        CZK => "CZK", 203, 2, "Czech Koruna";
+        DJF => "DJF", 262, 0, "Djibouti Franc";
+        DKK => "DKK", 208, 2, "Danish Krone";
+        DOP => "DOP", 214, 2, "Dominican Peso";
+        DZD => "DZD", 12, 2, "Algerian Dinar";
+        EGP => "EGP", 818, 2, "Egyptian Pound";
+        ERN => "ERN", 232, 2, "Nakfa";
+        ETB => "ETB", 230, 2, "Ethiopian Birr";
+        EUR => "EUR", 978, 2, "Euro";
+        FJD => "FJD", 242, 2, "Fiji Dollar";
+        FKP => "FKP", 238, 2, "Falkland Islands Pound";
+        GBP => "GBP", 826, 2, "Pound Sterling";
+        GEL => "GEL", 981, 2, "Lari";
+        GHS => "GHS", 936, 2, "Ghana Cedi";
+        GIP => "GIP", 292, 2, "Gibraltar Pound";
+        GMD => "GMD", 270, 2, "Dalasi";
+        GNF => "GNF", 324, 0, "Guinean Franc";
+        GTQ => "GTQ", 320, 2, "Quetzal";
+        GYD => "GYD", 328, 2, "Guyana Dollar";
+        HKD => "HKD", 344, 2, "Hong Kong Dollar";
+        HNL => "HNL", 340, 2, "Lempira";
+        HRK => "HRK", 191, 2, "Kuna";
+        HTG => "HTG", 332, 2, "Gourde";
+        HUF => "HUF", 348, 2, "Forint";
+        IDR => "IDR", 360, 2, "Rupiah";
+        ILS => "ILS", 376, 2, "New Israeli Sheqel";
+        INR => "INR", 356, 2, "Indian Rupee";
+        IQD => "IQD", 368, 3, "Iraqi Dinar";
+        IRR => "IRR", 364, 2, "Iranian Rial";
+        ISK => "ISK", 352, 0, "Iceland Krona";
+        JMD => "JMD", 388, 2, "Jamaican Dollar";
+        JOD => "JOD", 400, 3, "Jordanian Dinar";
+        JPY => "JPY", 392, 0, "Yen";
+        KES => "KES", 404, 2, "Kenyan Shilling";
+        KGS => "KGS", 417, 2, "Som";
+        KHR => "KHR", 116, 2, "Riel";
+        KMF => "KMF", 174, 0, "Comorian Franc";
+        KPW => "KPW", 408, 2, "North Korean Won";
+        KRW => "KRW", 410, 0, "Won";
+        KWD => "KWD", 414, 3, "Kuwaiti Dinar";
+        KYD => "KYD", 136, 2, "Cayman Islands Dollar";
+        KZT => "KZT", 398, 2, "Tenge";
+        LAK => "LAK", 418, 2, "Lao Kip";
+        LBP => "LBP", 422, 2, "Lebanese Pound";
+        LKR => "LKR", 144, 2, "Sri Lanka Rupee";
+        LRD => "LRD", 430, 2, "Liberian Dollar";
+        LSL => "LSL", 426, 2, "Loti";
+        LYD => "LYD", 434, 3, "Libyan Dinar";
+        MAD => "MAD", 504, 2, "Moroccan Dirham";
+        MDL => "MDL", 498, 2, "Moldovan Leu";
+        MGA => "MGA", 969, 1, "Malagasy Ariary";
+        MKD => "MKD", 807, 2, "Denar";
+        MMK => "MMK", 104, 2, "Kyat";
+        MNT => "MNT", 496, 2, "Tugrik";
+        MOP => "MOP", 446, 2, "Pataca";
+        MRU => "MRU", 929, 1, "Ouguiya";
+        MUR => "MUR", 480, 2, "Mauritius Rupee";
+        MVR => "MVR", 462, 2, "Rufiyaa";
+        MWK => "MWK", 454, 2, "Malawi Kwacha";
+        MXN => "MXN", 484, 2, "Mexican Peso";
+        MXV => "MXV", 979, 2, "Mexican Unidad de Inversion (UDI)";
+        MYR => "MYR", 458, 2, "Malaysian Ringgit";
+        MZN => "MZN", 943, 2, "Mozambique Metical";
+        NAD => "NAD", 516, 2, "Namibia Dollar";
+        NGN => "NGN", 566, 2, "Naira";
+        NIO => "NIO", 558, 2, "Cordoba Oro";
+        NOK => "NOK", 578, 2, "Norwegian Krone";
+        NPR => "NPR", 524, 2, "Nepalese Rupee";
+        NZD => "NZD", 554, 2, "New Zealand Dollar";
+        OMR => "OMR", 512, 3, "Rial Omani";
+        PAB => "PAB", 590, 2, "Balboa";
+        PEN => "PEN", 604, 2, "Sol";
+        PGK => "PGK", 598, 2, "Kina";
+        PHP => "PHP", 608, 2, "Philippine Peso";
+        PKR => "PKR", 586, 2, "Pakistan Rupee";
+        PLN => "PLN", 985, 2, "Zloty";
+        PYG => "PYG", 600, 0, "Guarani";
+        QAR => "QAR", 634, 2, "Qatari Rial";
+        RON => "RON", 946, 2, "Romanian Leu";
+        RSD => "RSD", 941, 2, "Serbian Dinar";
+        RUB => "RUB", 643, 2, "Russian Ruble";
+        RWF => "RWF", 646, 0, "Rwanda Franc";
+        SAR => "SAR", 682, 2, "Saudi Riyal";
+        SBD => "SBD", 90, 2, "Solomon Islands Dollar";
+        SCR => "SCR", 690, 2, "Seychelles Rupee";
+        SDG => "SDG", 938, 2, "Sudanese Pound";
+        SEK => "SEK", 752, 2, "Swedish Krona";
+        SGD => "SGD", 702, 2, "Singapore Dollar";
+        SHP => "SHP", 654, 2, "Saint Helena Pound";
+        SLL => "SLL", 694, 2, "Leone";
+        SOS => "SOS", 706, 2, "Somali Shilling";
+        SRD => "SRD", 968, 2, "Surinam Dollar";
+        SSP => "SSP", 728, 2, "South Sudanese Pound";
+        STN => "STN", 930, 2, "Dobra";
+        SVC => "SVC", 222, 2, "El Salvador Colon";
+        SYP => "SYP", 760, 2, "Syrian Pound";
+        SZL => "SZL", 748, 2, "Lilangeni";
+        THB => "THB", 764, 2, "Baht";
+        TJS => "TJS", 972, 2, "Somoni";
+        TMT => "TMT", 934, 2, "Turkmenistan New Manat";
+        TND => "TND", 788, 3, "Tunisian Dinar";
+        TOP => "TOP", 776, 2, "Pa'anga";
+        TRY => "TRY", 949, 2, "Turkish Lira";
+        TTD => "TTD", 780, 2, "Trinidad and Tobago Dollar";
+        TWD => "TWD", 901, 2, "New Taiwan Dollar";
+        TZS => "TZS", 834, 2, "Tanzanian Shilling";
+        UAH => "UAH", 980, 2, "Hryvnia";
+        UGX => "UGX", 800, 0, "Uganda Shilling";
+        USD => "USD", 840, 2, "US Dollar";
+        UYI => "UYI", 940, 0, "Uruguay Peso en Unidades Indexadas (UI)";
+        UYU => "UYU", 858, 2, "Peso Uruguayo";
+        UYW => "UYW", 927, 4, "Unidad Previsional";
+        UZS => "UZS", 860, 2, "Uzbekistan Sum";
+        VES => "VES", 928, 2, "Bolivar Soberano";
+        VND => "VND", 704, 0, "Dong";
+        VUV => "VUV", 548, 0, "Vatu";
+        WST => "WST", 882, 2, "Tala";
+        XAF => "XAF", 950, 0, "CFA Franc BEAC";
+        XCD => "XCD", 951, 2, "East Caribbean Dollar";
+        XDR => "XDR", 960, 0, "SDR (Special Drawing Right)";
+        XOF => "XOF", 952, 0, "CFA Franc BCEAO";
+        XPF => "XPF", 953, 0, "CFP Franc";
+        XSU => "XSU", 994, 2, "Sucre";
+        YER => "YER", 886, 2, "Yemeni Rial";
+        ZAR => "ZAR", 710, 2, "Rand";
+        ZMW => "ZMW", 967, 2, "Zambian Kwacha";
+        ZWL => "ZWL", 932, 2, "Zimbabwe Dollar";
+    }
+}
+
+impl FromStr for Currency {
+    type Err = String;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Currency::from_code(code).ok_or_else(|| format!("Unknown currency {}", code))
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = std::borrow::Cow::<str>::deserialize(deserializer)?;
+
+        Currency::from_str(&code).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::currencies::Currency;
+    use std::str::FromStr;
+
+    #[test]
+    fn lookup() {
+        assert_eq!(Currency::from_str("usd"), Ok(Currency::USD));
+        assert_eq!(Currency::from_numeric_code(484), Some(Currency::MXN));
+        assert_eq!(Currency::JPY.minor_digits(), 0);
+        assert_eq!(Currency::KWD.minor_digits(), 3);
+        assert_eq!(Currency::EUR.numeric_code(), 978);
+        assert_eq!(Currency::USD.name(), "US Dollar");
+        assert!(Currency::from_str("XXX1").is_err());
+    }
+
+    #[test]
+    fn codes_are_unique() {
+        for (i, currency) in Currency::VALUES.iter().enumerate() {
+            assert_eq!(Currency::from_code(currency.code()), Some(*currency));
+            assert_eq!(Currency::from_numeric_code(currency.numeric_code()), Some(*currency));
+            assert!(Currency::VALUES[..i].iter().all(|other| other.code() < currency.code()));
+        }
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&Currency::BRL).unwrap(), r#""BRL""#);
+        assert_eq!(serde_json::from_str::<Currency>(r#""COP""#).unwrap(), Currency::COP);
+        assert!(serde_json::from_str::<Currency>(r#""ABC""#).is_err());
+    }
+}
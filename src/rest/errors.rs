@@ -1,27 +1,248 @@
-use error_chain::error_chain;
-use serde::Deserialize;
+use crate::rest::callback::MonetixErrorItemModel;
+use crate::rest::gate::validation::ValidationErrors;
+use reqwest::StatusCode;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
 use std::collections::HashMap;
 
-#[derive(Debug, Deserialize)]
+/// Body of a rejected request (400, 422).
+#[derive(Debug, Clone, Deserialize)]
 pub struct MonetixContentError {
-    pub errors: HashMap<String, String>,
+    pub status: Option<String>,
+    pub request_id: Option<String>,
+    pub project_id: Option<u64>,
+    pub payment_id: Option<String>,
+    /// Monetix error code, sent either as a number or as a string.
+    #[serde(default, deserialize_with = "deserialize_code")]
+    pub code: Option<String>,
+    pub message: Option<String>,
+    /// Per-field errors, sent either as an array of items or as a field to message map.
+    #[serde(default, deserialize_with = "deserialize_errors")]
+    pub errors: Vec<MonetixErrorItemModel>,
 }
 
-error_chain! {
-    errors {
-       MonetixError(response: MonetixContentError)
+impl MonetixContentError {
+    /// Pairs of field name and message for every field error.
+    pub fn field_errors(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.errors.iter().filter_map(|item| {
+            let field = item.field.as_deref()?;
+            let message = item.message.as_deref().or(item.description.as_deref())?;
+
+            Some((field, message))
+        })
     }
-    types {
-        Error, ErrorKind, ResultExt, Result;
+}
+
+impl std::fmt::Display for MonetixContentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "code {}: {}",
+            self.code.as_deref().unwrap_or("-"),
+            self.message.as_deref().unwrap_or("-")
+        )?;
+
+        for (field, message) in self.field_errors() {
+            write!(f, "; {}: {}", field, message)?;
+        }
+
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request_id {})", request_id)?;
+        }
+
+        Ok(())
     }
+}
+
+fn deserialize_code<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let value = Option::<Value>::deserialize(deserializer)?;
+
+    Ok(match value {
+        Some(Value::String(code)) => Some(code),
+        Some(Value::Number(code)) => Some(code.to_string()),
+        _ => None,
+    })
+}
+
+fn deserialize_errors<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<MonetixErrorItemModel>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Errors {
+        List(Vec<MonetixErrorItemModel>),
+        Map(HashMap<String, String>),
+    }
+
+    let errors = match Option::<Errors>::deserialize(deserializer)? {
+        Some(Errors::List(items)) => items,
+        Some(Errors::Map(map)) => map
+            .into_iter()
+            .map(|(field, message)| MonetixErrorItemModel {
+                code: None,
+                description: None,
+                field: Some(field),
+                message: Some(message),
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    Ok(errors)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MonetixError {
+    /// Request was not sent or response was not received: connection, timeout, body read errors.
+    #[error("Transport error: {source}")]
+    Transport {
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+        /// The failure is temporary, e.g. a connection error or a timeout.
+        retryable: bool,
+    },
+    /// Monetix answered with an unexpected status code.
+    #[error("Received response code {status} from {url}: {body}")]
+    Http {
+        status: StatusCode,
+        url: String,
+        body: String,
+    },
+    /// Monetix rejected the request data.
+    #[error("Validation error: {0}")]
+    Validation(Box<MonetixContentError>),
+    /// Request data failed local validation and was not sent.
+    #[error("Invalid request: {0}")]
+    InvalidRequest(ValidationErrors),
+    /// Response has a success status but its body doesn't match the expected model.
+    #[error("Failed to deserialize body from {url}: {source}. Body: {body}")]
+    Deserialize {
+        url: String,
+        body: String,
+        #[source]
+        source: serde_json::Error,
+    },
+    /// Healthcheck answered with a body that is not a host name.
+    #[error("Invalid payment host from {url}: {body:?}")]
+    InvalidPaymentHost { url: String, body: String },
+    /// The healthcheck client was configured without urls.
+    #[error("No healthcheck urls configured")]
+    NoHealthcheckUrls,
+    #[error("Failed to serialize request: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("Failed to sign request: {0}")]
+    Signing(String),
+    #[error("Failed to encrypt request: {0}")]
+    Encryption(String),
+}
+
+impl From<reqwest::Error> for MonetixError {
+    fn from(err: reqwest::Error) -> Self {
+        let retryable = err.is_timeout() || err.is_connect();
+
+        MonetixError::Transport {
+            source: Box::new(err),
+            retryable,
+        }
+    }
+}
+
+impl MonetixError {
+    /// Failure of a custom `MonetixTransport`, e.g. a connection reset or a timeout.
+    pub fn transport(source: impl Into<Box<dyn std::error::Error + Send + Sync>>, retryable: bool) -> Self {
+        MonetixError::Transport {
+            source: source.into(),
+            retryable,
+        }
+    }
+
+    /// Whether sending the same request again may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            MonetixError::Transport { retryable, .. } => *retryable,
+            MonetixError::Http { status, .. } => matches!(
+                *status,
+                StatusCode::TOO_MANY_REQUESTS
+                    | StatusCode::INTERNAL_SERVER_ERROR
+                    | StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            MonetixError::Validation(_)
+            | MonetixError::InvalidRequest(_)
+            | MonetixError::Deserialize { .. }
+            | MonetixError::InvalidPaymentHost { .. }
+            | MonetixError::NoHealthcheckUrls
+            | MonetixError::Serialize(_)
+            | MonetixError::Signing(_)
+            | MonetixError::Encryption(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::errors::{MonetixContentError, MonetixError};
+    use reqwest::StatusCode;
+
+    fn http_error(status: StatusCode) -> MonetixError {
+        MonetixError::Http {
+            status,
+            url: "url".to_string(),
+            body: "body".to_string(),
+        }
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        assert!(http_error(StatusCode::SERVICE_UNAVAILABLE).is_retryable());
+        assert!(http_error(StatusCode::BAD_GATEWAY).is_retryable());
+        assert!(!http_error(StatusCode::BAD_REQUEST).is_retryable());
+        assert!(!http_error(StatusCode::UNAUTHORIZED).is_retryable());
+        assert!(!MonetixError::Signing("err".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn retryable_transport() {
+        assert!(MonetixError::transport("connection reset", true).is_retryable());
+        assert!(!MonetixError::transport("invalid certificate", false).is_retryable());
+    }
+
+    #[test]
+    fn content_error_with_list() {
+        let json = r#"
+{
+    "status": "error",
+    "request_id": "request_id",
+    "project_id": 1000,
+    "payment_id": "payment_id",
+    "code": "3",
+    "message": "Invalid request",
+    "errors": [{
+        "code": 1101,
+        "field": "customer.email",
+        "message": "Invalid email"
+    }]
+}"#;
+        let error: MonetixContentError = serde_json::from_str(json).unwrap();
+        let fields: Vec<_> = error.field_errors().collect();
+
+        assert_eq!(error.code.as_deref(), Some("3"));
+        assert_eq!(error.request_id.as_deref(), Some("request_id"));
+        assert_eq!(fields, vec![("customer.email", "Invalid email")]);
+        assert_eq!(
+            error.to_string(),
+            "code 3: Invalid request; customer.email: Invalid email (request_id request_id)"
+        );
+    }
+
+    #[test]
+    fn content_error_with_map() {
+        let json = r#"{"code": 2, "errors": {"payment.amount": "Must be positive"}}"#;
+        let error: MonetixContentError = serde_json::from_str(json).unwrap();
+        let fields: Vec<_> = error.field_errors().collect();
 
-    foreign_links {
-        ReqError(reqwest::Error);
-        InvalidHeaderError(reqwest::header::InvalidHeaderValue);
-        IoError(std::io::Error);
-        ParseFloatError(std::num::ParseFloatError);
-        UrlParserError(url::ParseError);
-        Json(serde_json::Error);
-        TimestampError(std::time::SystemTimeError);
+        assert_eq!(error.code.as_deref(), Some("2"));
+        assert_eq!(fields, vec![("payment.amount", "Must be positive")]);
     }
 }
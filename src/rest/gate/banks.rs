@@ -0,0 +1,36 @@
+/// Generates a bank list enum with the bank code, name and lookup by code.
+macro_rules! bank_codes {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $code:literal, $full_name:literal;)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)+
+        }
+
+        impl $name {
+            pub const fn code(&self) -> u16 {
+                match self {
+                    $($name::$variant => $code,)+
+                }
+            }
+
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $full_name,)+
+                }
+            }
+
+            pub const fn from_code(code: u16) -> Option<Self> {
+                match code {
+                    $($code => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,166 @@
+use crate::rest::gate::models::{MonetixGateRequest, MonetixGeneralModel, MonetixReturnUrlModel};
+use crate::rest::gate::recurring::MonetixRecurringModel;
+use crate::rest::gate::validation::{is_valid_ip, is_valid_pan, Validate, ValidationErrors};
+use crate::rest::money::Money;
+use crate::rest::signer::MonetixRequest;
+use serde_derive::{Deserialize, Serialize};
+
+impl MonetixRequest for MonetixCardSaleRequest {}
+
+impl MonetixGateRequest for MonetixCardSaleRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixCardSaleRequest {
+    pub general: MonetixGeneralModel,
+    pub customer: MonetixCardSaleCustomerModel,
+    pub payment: MonetixCardSalePaymentModel,
+    pub card: MonetixCardDataModel,
+    /// Where the customer is returned after the 3‑D Secure redirect
+    pub return_url: MonetixReturnUrlModel,
+    /// Registers a recurring with this sale, its id comes in the `recurring` object of the callback
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<MonetixRecurringModel>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixCardSaleCustomerModel {
+    pub id: String,
+    pub ip_address: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixCardSalePaymentModel {
+    #[serde(flatten)]
+    pub money: Money,
+    pub description: Option<String>,
+}
+
+/// Full card data. Debug output masks the pan and hides the cvv.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MonetixCardDataModel {
+    pub pan: String,
+    /// Expiry year in YYYY format
+    pub year: u32,
+    /// Expiry month from 1 to 12
+    pub month: u32,
+    pub card_holder: String,
+    pub cvv: String,
+}
+
+impl std::fmt::Debug for MonetixCardDataModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let masked_pan = if self.pan.is_ascii() && self.pan.len() > 10 {
+            format!(
+                "{}{}{}",
+                &self.pan[..6],
+                "*".repeat(self.pan.len() - 10),
+                &self.pan[self.pan.len() - 4..]
+            )
+        } else {
+            "*".repeat(self.pan.len())
+        };
+
+        f.debug_struct("MonetixCardDataModel")
+            .field("pan", &masked_pan)
+            .field("year", &self.year)
+            .field("month", &self.month)
+            .field("card_holder", &self.card_holder)
+            .field("cvv", &"***")
+            .finish()
+    }
+}
+
+impl MonetixRequest for MonetixThreeDsResultRequest {}
+
+impl MonetixGateRequest for MonetixThreeDsResultRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Completes 3‑D Secure with the data the issuer ACS posted back to the return url.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixThreeDsResultRequest {
+    pub general: MonetixGeneralModel,
+    /// The authentication result from the issuer ACS (PaRes)
+    pub pares: String,
+    /// Merchant technical data received in the `acs` object of the callback
+    pub md: String,
+}
+
+impl Validate for MonetixCardSaleRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("payment", &self.payment);
+        errors.nested("card", &self.card);
+    }
+}
+
+impl Validate for MonetixCardSaleCustomerModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.not_empty("id", &self.id);
+        errors.check(is_valid_ip(&self.ip_address), "ip_address", "must be a valid IPv4 or IPv6 address");
+    }
+}
+
+impl Validate for MonetixCardSalePaymentModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(self.money.minor() > 0, "amount", "must be positive");
+    }
+}
+
+impl Validate for MonetixCardDataModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(is_valid_pan(&self.pan), "pan", "must be a valid card number");
+        errors.check((1..=12).contains(&self.month), "month", "must be from 1 to 12");
+        errors.check(
+            (3..=4).contains(&self.cvv.len()) && self.cvv.bytes().all(|b| b.is_ascii_digit()),
+            "cvv",
+            "must have 3 or 4 digits",
+        );
+        errors.not_empty("card_holder", &self.card_holder);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::gate::card_sale::MonetixCardDataModel;
+    use crate::rest::gate::validation::Validate;
+
+    #[test]
+    fn card_debug_is_masked() {
+        let card = MonetixCardDataModel {
+            pan: "4000000000000077".to_string(),
+            year: 2030,
+            month: 1,
+            card_holder: "TEST TEST".to_string(),
+            cvv: "123".to_string(),
+        };
+        let debug = format!("{:?}", card);
+
+        assert!(debug.contains("400000******0077"));
+        assert!(!debug.contains("4000000000000077"));
+        assert!(!debug.contains("123"));
+    }
+
+    #[test]
+    fn invalid_card() {
+        let card = MonetixCardDataModel {
+            pan: "4000000000000078".to_string(),
+            year: 2030,
+            month: 13,
+            card_holder: "TEST TEST".to_string(),
+            cvv: "12".to_string(),
+        };
+
+        assert_eq!(
+            card.validate().unwrap_err().to_string(),
+            "pan: must be a valid card number; month: must be from 1 to 12; cvv: must have 3 or 4 digits"
+        );
+    }
+}
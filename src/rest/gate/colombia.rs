@@ -0,0 +1,322 @@
+use crate::rest::currencies::Currency;
+use crate::rest::gate::models::{MonetixGateRequest, MonetixGeneralModel};
+use crate::rest::gate::payout::MonetixPayoutPaymentModel;
+use crate::rest::gate::validation::{is_valid_email, is_valid_ip, Validate, ValidationErrors};
+use crate::rest::signer::MonetixRequest;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl MonetixRequest for MonetixColombiaBankPayoutRequest {}
+
+impl MonetixGateRequest for MonetixColombiaBankPayoutRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Payout to a Colombian bank account, amounts are in COP only.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixColombiaBankPayoutRequest {
+    pub general: MonetixGeneralModel,
+    pub customer: MonetixColombiaCustomerModel,
+    pub account: MonetixColombiaBankAccountModel,
+    pub payment: MonetixPayoutPaymentModel,
+}
+
+impl MonetixRequest for MonetixNequiPayoutRequest {}
+
+impl MonetixGateRequest for MonetixNequiPayoutRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Payout to a Nequi wallet, amounts are in COP only.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixNequiPayoutRequest {
+    pub general: MonetixGeneralModel,
+    pub customer: MonetixColombiaCustomerModel,
+    pub account: MonetixNequiAccountModel,
+    pub payment: MonetixPayoutPaymentModel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixColombiaCustomerModel {
+    pub id: String,
+    pub ip_address: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub identify: MonetixColombiaIdentifyModel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ColombiaDocType {
+    /// Cédula de ciudadanía, id of Colombian citizens
+    Cc,
+    /// Cédula de extranjería, id of foreign residents
+    Ce,
+    /// Número de identificación tributaria, taxpayer id of companies
+    Nit,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixColombiaIdentifyModel {
+    pub doc_type: ColombiaDocType,
+    pub doc_number: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ColombiaAccountType {
+    /// Cuenta de ahorros
+    Savings,
+    /// Cuenta corriente
+    Checking,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixColombiaBankAccountModel {
+    #[serde(rename = "type")]
+    pub account_type: ColombiaAccountType,
+    pub number: String,
+    #[serde(rename = "bank_id")]
+    pub bank: ColombiaBank,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixNequiAccountModel {
+    /// Colombian mobile number the wallet is registered to, 10 digits without the country code
+    pub number: String,
+}
+
+bank_codes! {
+    /// Colombian banks by the ACH code. Serialized as the code.
+    pub enum ColombiaBank {
+        BancoDeBogota => 1001, "BANCO DE BOGOTA";
+        BancoPopular => 1002, "BANCO POPULAR";
+        Itau => 1006, "ITAU";
+        Bancolombia => 1007, "BANCOLOMBIA";
+        Citibank => 1009, "CITIBANK";
+        GnbSudameris => 1012, "BANCO GNB SUDAMERIS";
+        BbvaColombia => 1013, "BBVA COLOMBIA";
+        ScotiabankColpatria => 1019, "SCOTIABANK COLPATRIA";
+        BancoDeOccidente => 1023, "BANCO DE OCCIDENTE";
+        BancoCajaSocial => 1032, "BANCO CAJA SOCIAL";
+        BancoAgrario => 1040, "BANCO AGRARIO";
+        Davivienda => 1051, "DAVIVIENDA";
+        AvVillas => 1052, "BANCO AV VILLAS";
+        Bancamia => 1059, "BANCAMIA";
+        BancoPichincha => 1060, "BANCO PICHINCHA";
+        Bancoomeva => 1061, "BANCOOMEVA";
+        BancoFalabella => 1062, "BANCO FALABELLA";
+        BancoFinandina => 1063, "BANCO FINANDINA";
+        Coopcentral => 1066, "BANCO COOPERATIVO COOPCENTRAL";
+        BancoSerfinanza => 1069, "BANCO SERFINANZA";
+        Cfa => 1283, "CFA COOPERATIVA FINANCIERA";
+        Confiar => 1292, "CONFIAR";
+        Nequi => 1507, "NEQUI";
+        Daviplata => 1551, "DAVIPLATA";
+        Movii => 1801, "MOVII";
+    }
+}
+
+impl Serialize for ColombiaBank {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for ColombiaBank {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = u16::deserialize(deserializer)?;
+
+        ColombiaBank::from_code(code).ok_or_else(|| D::Error::custom(format!("Unknown bank code {}", code)))
+    }
+}
+
+fn is_digits(value: &str, len: std::ops::RangeInclusive<usize>) -> bool {
+    len.contains(&value.len()) && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// 9 digits with an optional DIAN check digit, e.g. `800197268` or `800197268-4`.
+pub fn is_valid_nit(value: &str) -> bool {
+    const WEIGHTS: [u32; 9] = [3, 7, 13, 17, 19, 23, 29, 37, 41];
+
+    let (number, check_digit) = match value.split_once('-') {
+        Some((number, check_digit)) => (number, Some(check_digit)),
+        None if value.len() == 10 && value.is_ascii() => (&value[..9], value.get(9..)),
+        None => (value, None),
+    };
+
+    if !is_digits(number, 9..=9) {
+        return false;
+    }
+
+    let Some(check_digit) = check_digit else {
+        return true;
+    };
+
+    if !is_digits(check_digit, 1..=1) {
+        return false;
+    }
+
+    let sum: u32 = number
+        .bytes()
+        .rev()
+        .zip(WEIGHTS)
+        .map(|(digit, weight)| (digit - b'0') as u32 * weight)
+        .sum();
+    let expected = match sum % 11 {
+        remainder @ (0 | 1) => remainder,
+        remainder => 11 - remainder,
+    };
+
+    check_digit.parse() == Ok(expected)
+}
+
+/// 10-digit mobile number starting with 3, e.g. `3001234567`.
+pub fn is_valid_colombia_phone(value: &str) -> bool {
+    is_digits(value, 10..=10) && value.starts_with('3')
+}
+
+impl Validate for MonetixColombiaBankPayoutRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("account", &self.account);
+        errors.nested("payment", &self.payment);
+        errors.check(
+            self.payment.money.currency() == Currency::COP,
+            "payment.currency",
+            "must be COP",
+        );
+    }
+}
+
+impl Validate for MonetixNequiPayoutRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("account", &self.account);
+        errors.nested("payment", &self.payment);
+        errors.check(
+            self.payment.money.currency() == Currency::COP,
+            "payment.currency",
+            "must be COP",
+        );
+    }
+}
+
+impl Validate for MonetixColombiaCustomerModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.not_empty("id", &self.id);
+        errors.check(is_valid_ip(&self.ip_address), "ip_address", "must be a valid IPv4 or IPv6 address");
+        errors.not_empty("first_name", &self.first_name);
+        errors.not_empty("last_name", &self.last_name);
+        errors.check(is_valid_email(&self.email), "email", "must be a valid email");
+        errors.nested("identify", &self.identify);
+    }
+}
+
+impl Validate for MonetixColombiaIdentifyModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        match self.doc_type {
+            ColombiaDocType::Cc => errors.check(is_digits(&self.doc_number, 6..=10), "doc_number", "must have from 6 to 10 digits"),
+            ColombiaDocType::Ce => errors.check(is_digits(&self.doc_number, 6..=12), "doc_number", "must have from 6 to 12 digits"),
+            ColombiaDocType::Nit => errors.check(is_valid_nit(&self.doc_number), "doc_number", "must be a valid NIT"),
+        }
+    }
+}
+
+impl Validate for MonetixColombiaBankAccountModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(is_digits(&self.number, 6..=20), "number", "must have from 6 to 20 digits");
+    }
+}
+
+impl Validate for MonetixNequiAccountModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(
+            is_valid_colombia_phone(&self.number),
+            "number",
+            "must be a 10-digit mobile number starting with 3",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::currencies::Currency;
+    use crate::rest::gate::colombia::*;
+    use crate::rest::gate::test_fixtures::{colombia_customer, general, payout_payment, sign_string};
+    use crate::rest::gate::validation::Validate;
+
+    #[test]
+    fn nit() {
+        assert!(is_valid_nit("800197268"));
+        assert!(is_valid_nit("800197268-4"));
+        assert!(is_valid_nit("8001972684"));
+        assert!(is_valid_nit("890903938-8"));
+        assert!(!is_valid_nit("800197268-5"));
+        assert!(!is_valid_nit("80019726"));
+        assert!(!is_valid_nit("800197268-"));
+    }
+
+    #[test]
+    fn phone() {
+        assert!(is_valid_colombia_phone("3001234567"));
+        assert!(!is_valid_colombia_phone("6011234567"));
+        assert!(!is_valid_colombia_phone("573001234567"));
+    }
+
+    #[test]
+    fn bank_payout() {
+        let request = MonetixColombiaBankPayoutRequest {
+            general: general(),
+            customer: colombia_customer(),
+            account: MonetixColombiaBankAccountModel {
+                account_type: ColombiaAccountType::Savings,
+                number: "12345678901".to_string(),
+                bank: ColombiaBank::Bancolombia,
+            },
+            payment: payout_payment(5000000, Currency::COP),
+        };
+        let sign_string = sign_string(&request);
+
+        assert!(request.validate().is_ok());
+        assert!(sign_string.contains("account:bank_id:1007;account:number:12345678901;account:type:savings"));
+        assert!(sign_string.contains("customer:identify:doc_type:CC"));
+        assert_eq!(
+            serde_json::from_str::<ColombiaBank>("1051").unwrap(),
+            ColombiaBank::Davivienda
+        );
+        assert!(serde_json::from_str::<ColombiaBank>("1").is_err());
+    }
+
+    #[test]
+    fn invalid_nequi_payout() {
+        let mut customer = colombia_customer();
+        customer.identify = MonetixColombiaIdentifyModel {
+            doc_type: ColombiaDocType::Nit,
+            doc_number: "800197268-5".to_string(),
+        };
+        let request = MonetixNequiPayoutRequest {
+            general: general(),
+            customer,
+            account: MonetixNequiAccountModel {
+                number: "+573001234567".to_string(),
+            },
+            payment: payout_payment(1000, Currency::USD),
+        };
+
+        let errors = request.validate().unwrap_err();
+        let fields: Vec<_> = errors.errors().iter().map(|error| error.field.as_str()).collect();
+
+        assert_eq!(
+            fields,
+            vec!["customer.identify.doc_number", "account.number", "payment.currency"]
+        );
+    }
+}
@@ -1,23 +1,39 @@
+use crate::rest::gate::payout_method::PayoutMethod;
 use http::Method;
 
 pub enum MonetixGateEndpoint {
     CreateInvoicePayment,
-    MakePayout(String),
+    MakePayout(PayoutMethod),
+    PaymentStatus,
+    /// Full or partial card refund, depending on whether the amount is set
+    CardRefund,
+    CardCapture,
+    CardCancel,
+    CardSale,
+    CardThreeDsResult,
+    CardRecurring,
+    CardRecurringUpdate,
+    CardRecurringCancel,
+    CardTokenSale,
+    PixSale,
 }
 
 impl From<&MonetixGateEndpoint> for String {
     fn from(item: &MonetixGateEndpoint) -> Self {
         match item {
             MonetixGateEndpoint::CreateInvoicePayment => "/v2/payment/invoice/create".to_string(),
-            MonetixGateEndpoint::MakePayout(payment_method) => {
-                if payment_method == "nequi" {
-                    format!("/v2/payment/wallet/{payment_method}/payout")
-                } else if payment_method == "card" {
-                    format!("/v2/payment/{payment_method}/payout")
-                } else {
-                    format!("/v2/payment/bank-transfer/{payment_method}/payout")
-                }
-            }
+            MonetixGateEndpoint::PaymentStatus => "/v2/payment/status".to_string(),
+            MonetixGateEndpoint::CardRefund => "/v2/payment/card/refund".to_string(),
+            MonetixGateEndpoint::CardCapture => "/v2/payment/card/capture".to_string(),
+            MonetixGateEndpoint::CardCancel => "/v2/payment/card/cancel".to_string(),
+            MonetixGateEndpoint::CardSale => "/v2/payment/card/sale".to_string(),
+            MonetixGateEndpoint::CardThreeDsResult => "/v2/payment/card/3ds_result".to_string(),
+            MonetixGateEndpoint::CardRecurring => "/v2/payment/card/recurring".to_string(),
+            MonetixGateEndpoint::CardRecurringUpdate => "/v2/payment/card/recurring/update".to_string(),
+            MonetixGateEndpoint::CardRecurringCancel => "/v2/payment/card/recurring/cancel".to_string(),
+            MonetixGateEndpoint::CardTokenSale => "/v2/payment/card/sale/token".to_string(),
+            MonetixGateEndpoint::PixSale => "/v2/payment/bank-transfer/pix/sale".to_string(),
+            MonetixGateEndpoint::MakePayout(payout_method) => payout_method.path().to_string(),
         }
     }
 }
@@ -27,6 +43,17 @@ impl MonetixGateEndpoint {
         match &self {
             MonetixGateEndpoint::CreateInvoicePayment => Method::POST,
             MonetixGateEndpoint::MakePayout(_) => Method::POST,
+            MonetixGateEndpoint::PaymentStatus => Method::POST,
+            MonetixGateEndpoint::CardRefund => Method::POST,
+            MonetixGateEndpoint::CardCapture => Method::POST,
+            MonetixGateEndpoint::CardCancel => Method::POST,
+            MonetixGateEndpoint::CardSale => Method::POST,
+            MonetixGateEndpoint::CardThreeDsResult => Method::POST,
+            MonetixGateEndpoint::CardRecurring => Method::POST,
+            MonetixGateEndpoint::CardRecurringUpdate => Method::POST,
+            MonetixGateEndpoint::CardRecurringCancel => Method::POST,
+            MonetixGateEndpoint::CardTokenSale => Method::POST,
+            MonetixGateEndpoint::PixSale => Method::POST,
         }
     }
 }
@@ -1,4 +1,6 @@
 use chrono::{Datelike, Timelike};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
 use rust_extensions::date_time::DateTimeAsMicroseconds;
 
 pub fn into_date_string(date: DateTimeAsMicroseconds) -> String {
@@ -7,30 +9,20 @@ pub fn into_date_string(date: DateTimeAsMicroseconds) -> String {
     format!("{}-{:02}-{:02}T{:02}:{:02}:{:02}+00", date.year(), date.month(), date.day(), date.hour(), date.minute(), date.second())
 }
 
-pub fn into_minor_amount(amount: f64, minor_digits: usize) -> u64 {
-    let amount_str = amount.to_string();
-    let splits: Vec<&str> = amount_str.split('.').collect();
-    let major = splits[0];
+/// Converts the amount into minor units dropping extra digits. Returns `None` for NaN,
+/// infinite, negative and overflowing amounts. Prefer `Money` which doesn't go through floats.
+pub fn into_minor_amount(amount: f64, minor_digits: usize) -> Option<u64> {
+    let amount = Decimal::from_f64(amount)?;
+    let factor = 10_u64.checked_pow(minor_digits as u32)?;
 
-    let result = if splits.len() == 2 {
-        let digits = splits[1].chars().chain(std::iter::repeat('0'));
-        let minor: String = digits.take(minor_digits).collect();
-
-        format!("{major}{minor}")
-    } else if splits.len() == 1 {
-        let minor: String = "0".repeat(minor_digits);
-
-        format!("{major}{minor}")
-    } else {
-        panic!("impossible");
-    };
-
-    result.parse().unwrap()
+    amount
+        .trunc_with_scale(minor_digits as u32)
+        .checked_mul(Decimal::from(factor))?
+        .to_u64()
 }
 
 pub fn from_minor_amount(amount_minor: i64, decimal_places: u32) -> f64 {
-    let divisor = 10_i64.pow(decimal_places);
-    amount_minor as f64 / divisor as f64
+    Decimal::new(amount_minor, decimal_places).to_f64().unwrap_or(0.0)
 }
 
 #[cfg(test)]
@@ -44,7 +36,7 @@ mod tests {
         let minor_digits = 2;
         let minor_amount = into_minor_amount(source_amount, minor_digits);
 
-        assert_eq!(minor_amount, 1010);
+        assert_eq!(minor_amount, Some(1010));
     }
 
     #[test]
@@ -53,7 +45,7 @@ mod tests {
         let minor_digits = 2;
         let minor_amount = into_minor_amount(source_amount, minor_digits);
 
-        assert_eq!(minor_amount, 1010);
+        assert_eq!(minor_amount, Some(1010));
     }
 
     #[test]
@@ -62,7 +54,21 @@ mod tests {
         let minor_digits = 2;
         let minor_amount = into_minor_amount(source_amount, minor_digits);
 
-        assert_eq!(minor_amount, 1000);
+        assert_eq!(minor_amount, Some(1000));
+    }
+
+    #[test]
+    fn minor_amount_float_error() {
+        assert_eq!(into_minor_amount(0.1 + 0.2, 2), Some(30));
+        assert_eq!(into_minor_amount(1e-7, 2), Some(0));
+    }
+
+    #[test]
+    fn invalid_minor_amount() {
+        assert_eq!(into_minor_amount(f64::NAN, 2), None);
+        assert_eq!(into_minor_amount(f64::INFINITY, 2), None);
+        assert_eq!(into_minor_amount(-10.0, 2), None);
+        assert_eq!(into_minor_amount(1e30, 2), None);
     }
 
     #[test]
@@ -1,5 +1,22 @@
+#[macro_use]
+mod banks;
+
 pub mod endpoints;
 pub mod models;
 pub mod rest_client;
 pub mod fmt;
 pub mod payout;
+pub mod status;
+pub mod refund;
+pub mod two_step;
+pub mod card_sale;
+pub mod recurring;
+pub mod retry;
+pub mod validation;
+pub mod payout_method;
+pub mod spei;
+pub mod colombia;
+pub mod pix;
+
+#[cfg(test)]
+mod test_fixtures;
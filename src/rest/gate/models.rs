@@ -1,6 +1,13 @@
 use serde::{Deserialize, Serialize};
+use crate::rest::gate::validation::{Validate, ValidationErrors};
+use crate::rest::money::Money;
 use crate::rest::signer::{MonetixRequest};
 
+/// Gate request signed before sending, the signature goes into its `general` object.
+pub trait MonetixGateRequest: MonetixRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel;
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixCreateInvoicePaymentRequest {
     pub general: MonetixGeneralModel,
@@ -8,13 +15,35 @@ pub struct MonetixCreateInvoicePaymentRequest {
     pub payment: MonetixPaymentModel,
     pub return_url: MonetixReturnUrlModel,
     /// Card operation type for customer to pay. Default is sale.
-    //// Allowed values: sale, auth
-    pub card_operation_type: String,
+    pub card_operation_type: CardOperationType,
     pub send_email: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CardOperationType {
+    /// One-step payment, funds are debited immediately
+    Sale,
+    /// Two-step payment, funds are held until capture or cancel
+    Auth,
+}
+
 impl MonetixRequest for MonetixCreateInvoicePaymentRequest {}
 
+impl MonetixGateRequest for MonetixCreateInvoicePaymentRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+impl Validate for MonetixCreateInvoicePaymentRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("payment", &self.payment);
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixCreateInvoicePaymentResponse {
     #[serde(rename = "status")]
@@ -27,6 +56,10 @@ pub struct MonetixCreateInvoicePaymentResponse {
     pub payment_id: String,
 }
 
+/// Response to any asynchronous gate operation (payout, refund, etc.).
+/// The result of the operation comes later in a callback.
+pub type MonetixOperationResponse = MonetixCreateInvoicePaymentResponse;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixGeneralModel {
     pub project_id: u32,
@@ -35,6 +68,13 @@ pub struct MonetixGeneralModel {
     pub signature: String,
 }
 
+impl Validate for MonetixGeneralModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(self.project_id > 0, "project_id", "must be positive");
+        errors.not_empty("payment_id", &self.payment_id);
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixCustomerModel {
     pub id: String,
@@ -58,6 +98,12 @@ pub struct MonetixCustomerModel {
     //pub billing: Option<MonetixCustomerBillingModel>,
 }
 
+impl Validate for MonetixCustomerModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.not_empty("id", &self.id);
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixCustomerBillingModel {
     /// customer country in ISO 3166-1 alpha-2 format
@@ -70,14 +116,12 @@ pub struct MonetixCustomerBillingModel {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixPaymentModel {
-    /// Payment amount in minor currency units without any decimal point or comma except for cases
-    /// where the currency does not have any minor currency units. If the currency have no minor
-    /// units (that is, the number of digits for minor currency units is zero), then you must set
-    /// this parameter to the amount in the major currency units. For the information on whether
+    /// Payment amount in minor currency units and currency in ISO 4217 alpha-3 format.
+    /// If the currency have no minor units (that is, the number of digits for minor currency
+    /// units is zero), then the amount is in the major currency units. For the information on whether
     /// the currency has any minor units, see https://developers.trxhost.com/en/en_currency_codes.html
-    pub amount: u64,
-    /// Payment currency in ISO 4217 alpha-3 format
-    pub currency: String,
+    #[serde(flatten)]
+    pub money: Money,
     pub description: Option<String>,
     /// Extra payment description
     pub extra_param: Option<String>,
@@ -91,6 +135,12 @@ pub struct MonetixPaymentModel {
     //pub force_method: Option<String>,
 }
 
+impl Validate for MonetixPaymentModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(self.money.minor() > 0, "amount", "must be positive");
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixReturnUrlModel {
     pub success: Option<String>,
@@ -1,9 +1,17 @@
-use crate::rest::gate::models::{MonetixGeneralModel};
+use crate::rest::gate::models::{MonetixGateRequest, MonetixGeneralModel};
+use crate::rest::gate::validation::{is_valid_email, is_valid_ip, is_valid_pan, Validate, ValidationErrors};
+use crate::rest::money::Money;
 use crate::rest::signer::MonetixRequest;
 use serde_derive::{Deserialize, Serialize};
 
 impl MonetixRequest for MonetixCardPayoutRequest {}
 
+impl MonetixGateRequest for MonetixCardPayoutRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixCardPayoutRequest {
     pub general: MonetixGeneralModel,
@@ -27,6 +35,12 @@ pub struct MonetixPayoutRequest {
 
 impl MonetixRequest for MonetixPayoutRequest {}
 
+impl MonetixGateRequest for MonetixPayoutRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixCustomerPayoutModel {
     pub id: String,
@@ -61,6 +75,148 @@ pub struct MonetixCustomerAccountModel {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MonetixPayoutPaymentModel {
-    pub amount: u64,
-    pub currency: String,
+    /// Payout amount in minor currency units and currency in ISO 4217 alpha-3 format
+    #[serde(flatten)]
+    pub money: Money,
+}
+
+impl Validate for MonetixCardPayoutRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("payment", &self.payment);
+        errors.nested("card", &self.card);
+    }
+}
+
+impl Validate for MonetixPayoutRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("account", &self.account);
+        errors.nested("payment", &self.payment);
+    }
+}
+
+impl Validate for MonetixCardModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(is_valid_pan(&self.pan), "pan", "must be a valid card number");
+    }
+}
+
+impl Validate for MonetixCustomerPayoutModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.not_empty("id", &self.id);
+        errors.check(is_valid_ip(&self.ip_address), "ip_address", "must be a valid IPv4 or IPv6 address");
+        errors.not_empty("first_name", &self.first_name);
+        errors.not_empty("last_name", &self.last_name);
+        errors.nested("identify", &self.identify);
+        errors.check(is_valid_email(&self.email), "email", "must be a valid email");
+    }
+}
+
+impl Validate for MonetixCustomerIdentifyModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.not_empty("doc_number", &self.doc_number);
+    }
+}
+
+impl Validate for MonetixCustomerAccountModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.not_empty("number", &self.number);
+    }
+}
+
+impl Validate for MonetixPayoutPaymentModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(self.money.minor() > 0, "amount", "must be positive");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::currencies::Currency;
+    use crate::rest::gate::payout::{
+        MonetixCardModel, MonetixCustomerAccountModel, MonetixCustomerIdentifyModel, MonetixCustomerPayoutModel,
+        MonetixPayoutRequest,
+    };
+    use crate::rest::gate::test_fixtures::{general, payout_payment};
+    use crate::rest::gate::validation::Validate;
+    use crate::rest::money::Money;
+
+    fn request() -> MonetixPayoutRequest {
+        MonetixPayoutRequest {
+            general: general(),
+            customer: MonetixCustomerPayoutModel {
+                id: "customer_id".to_string(),
+                ip_address: "127.0.0.1".to_string(),
+                first_name: "Juan".to_string(),
+                last_name: "Perez".to_string(),
+                identify: MonetixCustomerIdentifyModel {
+                    doc_type: None,
+                    doc_number: "doc_number".to_string(),
+                },
+                email: "juan@example.com".to_string(),
+            },
+            account: MonetixCustomerAccountModel {
+                account_type: None,
+                bank_id: None,
+                number: "number".to_string(),
+            },
+            payment: payout_payment(1000, Currency::MXN),
+        }
+    }
+
+    #[test]
+    fn valid_request() {
+        assert!(request().validate().is_ok());
+    }
+
+    #[test]
+    fn all_errors_at_once() {
+        let mut request = request();
+        request.customer.identify.doc_number = "".to_string();
+        request.customer.email = "juan".to_string();
+        request.customer.ip_address = "localhost".to_string();
+        request.payment.money = Money::from_minor(0, Currency::MXN);
+
+        let errors = request.validate().unwrap_err();
+        let fields: Vec<_> = errors.errors().iter().map(|error| error.field.as_str()).collect();
+
+        assert_eq!(
+            fields,
+            vec![
+                "customer.ip_address",
+                "customer.identify.doc_number",
+                "customer.email",
+                "payment.amount"
+            ]
+        );
+    }
+
+    #[test]
+    fn card_pan_luhn() {
+        let valid = MonetixCardModel {
+            pan: "4000000000000077".to_string(),
+        };
+        let mistyped = MonetixCardModel {
+            pan: "4000000000000078".to_string(),
+        };
+
+        assert!(valid.validate().is_ok());
+        assert_eq!(mistyped.validate().unwrap_err().to_string(), "pan: must be a valid card number");
+    }
+
+    #[test]
+    fn names_and_email_are_required() {
+        let mut request = request();
+        request.customer.first_name = "".to_string();
+        request.customer.last_name = " ".to_string();
+        request.customer.email = "".to_string();
+
+        assert_eq!(
+            request.validate().unwrap_err().to_string(),
+            "customer.first_name: must not be empty; customer.last_name: must not be empty; customer.email: must be a valid email"
+        );
+    }
 }
@@ -0,0 +1,136 @@
+use crate::rest::gate::payout::MonetixCustomerPayoutModel;
+use crate::rest::gate::validation::ValidationErrors;
+
+/// Payout method, defines the endpoint and the data Monetix expects for it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PayoutMethod {
+    Card,
+    /// Mexican bank transfer to a CLABE account
+    Spei,
+    /// Colombian wallet, the account number is the phone number
+    Nequi,
+    ColombiaBankTransfer,
+    /// Colombian bank transfer via PSE
+    Pse,
+    /// Brazilian instant payment to a PIX key
+    Pix,
+    /// Method not covered by this connector. `path` is the full endpoint path,
+    /// e.g. `/v2/payment/bank-transfer/peru/payout`
+    Other { path: String },
+}
+
+/// Where the payout destination goes in the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PayoutAccountKind {
+    /// `card` object, see `MonetixCardPayoutRequest`
+    Card,
+    /// `account` object, see `MonetixPayoutRequest`
+    Account,
+}
+
+/// Customer field that is mandatory only for some payout methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CustomerField {
+    DocType,
+}
+
+impl CustomerField {
+    /// Path of the field in the request
+    pub fn path(&self) -> &'static str {
+        match self {
+            CustomerField::DocType => "customer.identify.doc_type",
+        }
+    }
+
+    fn is_set(&self, customer: &MonetixCustomerPayoutModel) -> bool {
+        let value = match self {
+            CustomerField::DocType => customer.identify.doc_type.as_deref().unwrap_or(""),
+        };
+
+        !value.trim().is_empty()
+    }
+}
+
+impl PayoutMethod {
+    pub fn path(&self) -> &str {
+        match self {
+            PayoutMethod::Card => "/v2/payment/card/payout",
+            PayoutMethod::Spei => "/v2/payment/bank-transfer/spei/payout",
+            PayoutMethod::Nequi => "/v2/payment/wallet/nequi/payout",
+            PayoutMethod::ColombiaBankTransfer => "/v2/payment/bank-transfer/colombia/payout",
+            PayoutMethod::Pse => "/v2/payment/bank-transfer/pse/payout",
+            PayoutMethod::Pix => "/v2/payment/bank-transfer/pix/payout",
+            PayoutMethod::Other { path } => path,
+        }
+    }
+
+    pub fn account_kind(&self) -> PayoutAccountKind {
+        match self {
+            PayoutMethod::Card => PayoutAccountKind::Card,
+            _ => PayoutAccountKind::Account,
+        }
+    }
+
+    /// Customer fields Monetix rejects the payout without, on top of the ones every payout
+    /// requires: id, ip address, names, email and document number.
+    pub fn required_customer_fields(&self) -> &'static [CustomerField] {
+        match self {
+            PayoutMethod::Spei | PayoutMethod::ColombiaBankTransfer | PayoutMethod::Pse => &[CustomerField::DocType],
+            PayoutMethod::Card | PayoutMethod::Nequi | PayoutMethod::Pix | PayoutMethod::Other { .. } => &[],
+        }
+    }
+
+    /// Adds an error for every required customer field that is empty.
+    pub fn check_customer(&self, customer: &MonetixCustomerPayoutModel, errors: &mut ValidationErrors) {
+        for field in self.required_customer_fields() {
+            errors.check(field.is_set(customer), field.path(), "is required for this payout method");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::gate::payout::{MonetixCustomerIdentifyModel, MonetixCustomerPayoutModel};
+    use crate::rest::gate::payout_method::{PayoutAccountKind, PayoutMethod};
+    use crate::rest::gate::validation::ValidationErrors;
+
+    #[test]
+    fn paths() {
+        assert_eq!(PayoutMethod::Card.path(), "/v2/payment/card/payout");
+        assert_eq!(PayoutMethod::Nequi.path(), "/v2/payment/wallet/nequi/payout");
+        assert_eq!(PayoutMethod::Spei.path(), "/v2/payment/bank-transfer/spei/payout");
+
+        let other = PayoutMethod::Other {
+            path: "/v2/payment/bank-transfer/peru/payout".to_string(),
+        };
+        assert_eq!(other.path(), "/v2/payment/bank-transfer/peru/payout");
+        assert_eq!(other.account_kind(), PayoutAccountKind::Account);
+        assert_eq!(PayoutMethod::Card.account_kind(), PayoutAccountKind::Card);
+    }
+
+    #[test]
+    fn required_customer_fields() {
+        let customer = MonetixCustomerPayoutModel {
+            id: "customer_id".to_string(),
+            ip_address: "127.0.0.1".to_string(),
+            first_name: "Juan".to_string(),
+            last_name: "Perez".to_string(),
+            identify: MonetixCustomerIdentifyModel {
+                doc_type: None,
+                doc_number: "doc_number".to_string(),
+            },
+            email: "juan@example.com".to_string(),
+        };
+
+        let mut errors = ValidationErrors::new();
+        PayoutMethod::Card.check_customer(&customer, &mut errors);
+        assert!(errors.is_empty());
+
+        let mut errors = ValidationErrors::new();
+        PayoutMethod::Spei.check_customer(&customer, &mut errors);
+        assert_eq!(
+            errors.to_string(),
+            "customer.identify.doc_type: is required for this payout method"
+        );
+    }
+}
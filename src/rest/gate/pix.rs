@@ -0,0 +1,332 @@
+use crate::rest::currencies::Currency;
+use crate::rest::gate::models::{MonetixGateRequest, MonetixGeneralModel, MonetixReturnUrlModel};
+use crate::rest::gate::payout::MonetixPayoutPaymentModel;
+use crate::rest::gate::validation::{is_valid_email, is_valid_ip, Validate, ValidationErrors};
+use crate::rest::money::Money;
+use crate::rest::signer::MonetixRequest;
+use serde_derive::{Deserialize, Serialize};
+
+impl MonetixRequest for MonetixPixPayoutRequest {}
+
+impl MonetixGateRequest for MonetixPixPayoutRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Payout to a PIX key, amounts are in BRL only.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixPixPayoutRequest {
+    pub general: MonetixGeneralModel,
+    pub customer: MonetixPixCustomerModel,
+    pub account: MonetixPixAccountModel,
+    pub payment: MonetixPayoutPaymentModel,
+}
+
+impl MonetixRequest for MonetixPixPaymentRequest {}
+
+impl MonetixGateRequest for MonetixPixPaymentRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// PIX payment, amounts are in BRL only. The QR code payload for the customer comes in
+/// the `display_data` of the callback, see `MonetixCallbackModel::pix_qr_payload`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixPixPaymentRequest {
+    pub general: MonetixGeneralModel,
+    pub customer: MonetixPixCustomerModel,
+    pub payment: MonetixPixPaymentModel,
+    pub return_url: MonetixReturnUrlModel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixPixPaymentModel {
+    #[serde(flatten)]
+    pub money: Money,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixPixCustomerModel {
+    pub id: String,
+    pub ip_address: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub identify: MonetixPixIdentifyModel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixPixIdentifyModel {
+    /// CPF of a person or CNPJ of a company, digits only
+    pub doc_number: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PixKeyType {
+    /// 11-digit personal taxpayer id
+    Cpf,
+    /// 14-digit company taxpayer id
+    Cnpj,
+    Email,
+    /// Phone number in the +55DDNNNNNNNNN format
+    Phone,
+    /// Random key generated by the bank, a UUID
+    Evp,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixPixAccountModel {
+    #[serde(rename = "type")]
+    pub key_type: PixKeyType,
+    /// The PIX key itself
+    pub number: String,
+}
+
+fn is_digits(value: &str, len: usize) -> bool {
+    value.len() == len && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Check digit of the mod 11 scheme used by CPF and CNPJ.
+fn mod11_check_digit(digits: &[u32], weights: &[u32]) -> u32 {
+    let sum: u32 = digits.iter().zip(weights).map(|(digit, weight)| digit * weight).sum();
+
+    match sum % 11 {
+        0 | 1 => 0,
+        remainder => 11 - remainder,
+    }
+}
+
+fn to_digits(value: &str) -> Vec<u32> {
+    value.bytes().map(|b| (b - b'0') as u32).collect()
+}
+
+/// 11 digits with two check digits, e.g. `52998224725`.
+pub fn is_valid_cpf(value: &str) -> bool {
+    if !is_digits(value, 11) {
+        return false;
+    }
+
+    let digits = to_digits(value);
+
+    // the check digits also match for 000.000.000-00, 111.111.111-11 and so on
+    if digits.iter().all(|digit| *digit == digits[0]) {
+        return false;
+    }
+
+    mod11_check_digit(&digits[..9], &[10, 9, 8, 7, 6, 5, 4, 3, 2]) == digits[9]
+        && mod11_check_digit(&digits[..10], &[11, 10, 9, 8, 7, 6, 5, 4, 3, 2]) == digits[10]
+}
+
+/// 14 digits with two check digits, e.g. `11222333000181`.
+pub fn is_valid_cnpj(value: &str) -> bool {
+    if !is_digits(value, 14) {
+        return false;
+    }
+
+    let digits = to_digits(value);
+
+    if digits.iter().all(|digit| *digit == digits[0]) {
+        return false;
+    }
+
+    mod11_check_digit(&digits[..12], &[5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]) == digits[12]
+        && mod11_check_digit(&digits[..13], &[6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]) == digits[13]
+}
+
+/// `+55`, two-digit area code and an 8 or 9-digit number.
+pub fn is_valid_pix_phone(value: &str) -> bool {
+    let Some(number) = value.strip_prefix("+55") else {
+        return false;
+    };
+
+    is_digits(number, 10) || is_digits(number, 11)
+}
+
+/// UUID in the canonical 8-4-4-4-12 form.
+pub fn is_valid_evp(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    let lengths = [8, 4, 4, 4, 12];
+
+    groups.len() == lengths.len()
+        && groups
+            .iter()
+            .zip(lengths)
+            .all(|(group, len)| group.len() == len && group.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+impl PixKeyType {
+    pub fn is_valid_key(&self, key: &str) -> bool {
+        match self {
+            PixKeyType::Cpf => is_valid_cpf(key),
+            PixKeyType::Cnpj => is_valid_cnpj(key),
+            PixKeyType::Email => is_valid_email(key),
+            PixKeyType::Phone => is_valid_pix_phone(key),
+            PixKeyType::Evp => is_valid_evp(key),
+        }
+    }
+}
+
+impl Validate for MonetixPixPayoutRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("account", &self.account);
+        errors.nested("payment", &self.payment);
+        errors.check(
+            self.payment.money.currency() == Currency::BRL,
+            "payment.currency",
+            "must be BRL",
+        );
+    }
+}
+
+impl Validate for MonetixPixPaymentRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("payment", &self.payment);
+        errors.check(
+            self.payment.money.currency() == Currency::BRL,
+            "payment.currency",
+            "must be BRL",
+        );
+    }
+}
+
+impl Validate for MonetixPixPaymentModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(self.money.minor() > 0, "amount", "must be positive");
+    }
+}
+
+impl Validate for MonetixPixCustomerModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.not_empty("id", &self.id);
+        errors.check(is_valid_ip(&self.ip_address), "ip_address", "must be a valid IPv4 or IPv6 address");
+        errors.not_empty("first_name", &self.first_name);
+        errors.not_empty("last_name", &self.last_name);
+        errors.check(is_valid_email(&self.email), "email", "must be a valid email");
+        errors.nested("identify", &self.identify);
+    }
+}
+
+impl Validate for MonetixPixIdentifyModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(
+            is_valid_cpf(&self.doc_number) || is_valid_cnpj(&self.doc_number),
+            "doc_number",
+            "must be a valid CPF or CNPJ",
+        );
+    }
+}
+
+impl Validate for MonetixPixAccountModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        let message = match self.key_type {
+            PixKeyType::Cpf => "must be a valid CPF",
+            PixKeyType::Cnpj => "must be a valid CNPJ",
+            PixKeyType::Email => "must be a valid email",
+            PixKeyType::Phone => "must be a phone number in the +55DDNNNNNNNNN format",
+            PixKeyType::Evp => "must be a UUID",
+        };
+
+        errors.check(self.key_type.is_valid_key(&self.number), "number", message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::callback::MonetixCallbackModel;
+    use crate::rest::currencies::Currency;
+    use crate::rest::gate::pix::*;
+    use crate::rest::gate::test_fixtures::{general, payout_payment, pix_customer, sign_string};
+    use crate::rest::gate::validation::Validate;
+
+    #[test]
+    fn cpf_and_cnpj() {
+        assert!(is_valid_cpf("52998224725"));
+        assert!(!is_valid_cpf("52998224724"));
+        assert!(!is_valid_cpf("11111111111"));
+        assert!(!is_valid_cpf("529.982.247-25"));
+        assert!(is_valid_cnpj("11222333000181"));
+        assert!(!is_valid_cnpj("11222333000182"));
+        assert!(!is_valid_cnpj("00000000000000"));
+    }
+
+    #[test]
+    fn keys() {
+        assert!(PixKeyType::Email.is_valid_key("joao@example.com.br"));
+        assert!(PixKeyType::Phone.is_valid_key("+5511987654321"));
+        assert!(PixKeyType::Phone.is_valid_key("+551133334444"));
+        assert!(!PixKeyType::Phone.is_valid_key("11987654321"));
+        assert!(PixKeyType::Evp.is_valid_key("123e4567-e89b-12d3-a456-426614174000"));
+        assert!(!PixKeyType::Evp.is_valid_key("123e4567e89b12d3a456426614174000"));
+        assert!(!PixKeyType::Cpf.is_valid_key("joao@example.com.br"));
+    }
+
+    #[test]
+    fn payout_request() {
+        let mut request = MonetixPixPayoutRequest {
+            general: general(),
+            customer: pix_customer(),
+            account: MonetixPixAccountModel {
+                key_type: PixKeyType::Cpf,
+                number: "52998224725".to_string(),
+            },
+            payment: payout_payment(1000, Currency::BRL),
+        };
+
+        assert!(request.validate().is_ok());
+        assert!(sign_string(&request).contains("account:number:52998224725;account:type:CPF"));
+
+        request.account.key_type = PixKeyType::Phone;
+        request.customer.identify.doc_number = "123".to_string();
+
+        let errors = request.validate().unwrap_err();
+        let fields: Vec<_> = errors.errors().iter().map(|error| error.field.as_str()).collect();
+
+        assert_eq!(fields, vec!["customer.identify.doc_number", "account.number"]);
+    }
+
+    #[test]
+    fn payment_request() {
+        let request = MonetixPixPaymentRequest {
+            general: general(),
+            customer: pix_customer(),
+            payment: MonetixPixPaymentModel {
+                money: Money::from_minor(0, Currency::USD),
+                description: None,
+            },
+            return_url: MonetixReturnUrlModel {
+                success: None,
+                decline: None,
+                return_url: None,
+            },
+        };
+
+        assert_eq!(
+            request.validate().unwrap_err().to_string(),
+            "payment.amount: must be positive; payment.currency: must be BRL"
+        );
+    }
+
+    #[test]
+    fn qr_payload_from_callback() {
+        let json = r#"{"customer": {"id": "c"}, "payment": {"id": "p", "status": "awaiting customer", "type": "purchase", "sum": {}}, "project_id": 1, "signature": "", "display_data": [{"type": "qr_data", "title": "PIX", "data": "00020126580014br.gov.bcb.pix"}]}"#;
+        let callback: MonetixCallbackModel = serde_json::from_str(json).unwrap();
+
+        assert_eq!(callback.pix_qr_payload(), Some("00020126580014br.gov.bcb.pix"));
+
+        let encoded = json.replace(
+            r#"[{"type": "qr_data", "title": "PIX", "data": "00020126580014br.gov.bcb.pix"}]"#,
+            r#""[{\"type\": \"qr_data\", \"data\": \"000201\"}]""#,
+        );
+        let callback: MonetixCallbackModel = serde_json::from_str(&encoded).unwrap();
+
+        assert_eq!(callback.pix_qr_payload(), Some("000201"));
+    }
+}
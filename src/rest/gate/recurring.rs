@@ -0,0 +1,174 @@
+use crate::rest::gate::card_sale::{MonetixCardSaleCustomerModel, MonetixCardSalePaymentModel};
+use crate::rest::gate::models::{MonetixGateRequest, MonetixGeneralModel};
+use crate::rest::gate::validation::{Validate, ValidationErrors};
+use crate::rest::signer::MonetixRequest;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MonetixRecurringType {
+    /// Credential on file: charges initiated by the customer with saved card
+    #[serde(rename = "C")]
+    CredentialOnFile,
+    /// Regular charges on a fixed schedule, e.g. subscriptions
+    #[serde(rename = "R")]
+    Regular,
+    /// Charges initiated by the merchant without a fixed schedule
+    #[serde(rename = "U")]
+    Unscheduled,
+}
+
+/// Recurring settings. Sent with the first sale to register the recurring or
+/// with the update request to change the schedule.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixRecurringModel {
+    /// Must be true to register a recurring with the first sale
+    pub register: bool,
+    #[serde(rename = "type")]
+    pub recurring_type: MonetixRecurringType,
+    /// Day of the last charge in DD format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_day: Option<String>,
+    /// Month of the last charge in MM format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_month: Option<String>,
+    /// Year of the last charge in YYYY format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_year: Option<String>,
+    /// Charge period: D - day, W - week, M - month, Q - quarter, Y - year
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<String>,
+    /// Number of periods between charges
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<u32>,
+    /// Time of the charge in hh:mm:ss format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    /// Date of the first scheduled charge in DD-MM-YYYY format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// Amount of each scheduled charge in minor units of the currency of the sale that registered
+    /// the recurring. The recurring object has no currency of its own, so this is not a `Money`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+}
+
+impl MonetixRequest for MonetixRecurringPaymentRequest {}
+
+impl MonetixGateRequest for MonetixRecurringPaymentRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Charges a registered recurring.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixRecurringPaymentRequest {
+    pub general: MonetixGeneralModel,
+    pub customer: MonetixCardSaleCustomerModel,
+    pub payment: MonetixCardSalePaymentModel,
+    pub recurring_id: u64,
+}
+
+impl MonetixRequest for MonetixTokenSaleRequest {}
+
+impl MonetixGateRequest for MonetixTokenSaleRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Charges a saved card by the token from `MonetixAccountModel::token` of a previous callback.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixTokenSaleRequest {
+    pub general: MonetixGeneralModel,
+    pub customer: MonetixCardSaleCustomerModel,
+    pub payment: MonetixCardSalePaymentModel,
+    pub token: String,
+}
+
+impl MonetixRequest for MonetixRecurringUpdateRequest {}
+
+impl MonetixGateRequest for MonetixRecurringUpdateRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixRecurringUpdateRequest {
+    pub general: MonetixGeneralModel,
+    pub recurring_id: u64,
+    pub recurring: MonetixRecurringModel,
+}
+
+impl MonetixRequest for MonetixRecurringCancelRequest {}
+
+impl MonetixGateRequest for MonetixRecurringCancelRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixRecurringCancelRequest {
+    pub general: MonetixGeneralModel,
+    pub recurring_id: u64,
+}
+
+impl Validate for MonetixRecurringPaymentRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("payment", &self.payment);
+    }
+}
+
+impl Validate for MonetixTokenSaleRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("payment", &self.payment);
+        errors.not_empty("token", &self.token);
+    }
+}
+
+impl Validate for MonetixRecurringUpdateRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("recurring", &self.recurring);
+    }
+}
+
+impl Validate for MonetixRecurringModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(self.interval != Some(0), "interval", "must be positive");
+        errors.check(self.amount != Some(0), "amount", "must be positive");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::gate::recurring::{MonetixRecurringModel, MonetixRecurringType};
+    use crate::rest::gate::test_fixtures::sign_string;
+
+    #[test]
+    fn recurring_sign_string() {
+        let recurring = MonetixRecurringModel {
+            register: true,
+            recurring_type: MonetixRecurringType::Regular,
+            expiry_day: None,
+            expiry_month: None,
+            expiry_year: None,
+            period: Some("M".to_string()),
+            interval: Some(1),
+            time: None,
+            start_date: None,
+            amount: Some(1000),
+        };
+
+        assert_eq!(
+            sign_string(&recurring),
+            "amount:1000;interval:1;period:M;register:1;type:R"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::rest::gate::models::{MonetixGateRequest, MonetixGeneralModel};
+use crate::rest::gate::validation::{Validate, ValidationErrors};
+use crate::rest::money::Money;
+use crate::rest::signer::MonetixRequest;
+use serde_derive::{Deserialize, Serialize};
+
+impl MonetixRequest for MonetixRefundRequest {}
+
+impl MonetixGateRequest for MonetixRefundRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixRefundRequest {
+    pub general: MonetixGeneralModel,
+    pub payment: MonetixRefundPaymentModel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixRefundPaymentModel {
+    /// Refund amount and currency. If not set, the whole remaining payment amount is refunded.
+    #[serde(flatten)]
+    pub money: Option<Money>,
+    /// Comment or reason of the refund
+    pub description: String,
+    /// Refund id in the merchant system, must be unique within the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_refund_id: Option<String>,
+}
+
+impl MonetixRefundPaymentModel {
+    pub fn full(description: impl Into<String>) -> Self {
+        Self {
+            money: None,
+            description: description.into(),
+            merchant_refund_id: None,
+        }
+    }
+
+    pub fn partial(money: Money, description: impl Into<String>) -> Self {
+        Self {
+            money: Some(money),
+            description: description.into(),
+            merchant_refund_id: None,
+        }
+    }
+}
+
+impl Validate for MonetixRefundRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("payment", &self.payment);
+    }
+}
+
+impl Validate for MonetixRefundPaymentModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        if let Some(money) = &self.money {
+            errors.check(money.minor() > 0, "amount", "must be positive");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::currencies::Currency;
+    use crate::rest::gate::refund::MonetixRefundPaymentModel;
+    use crate::rest::gate::test_fixtures::sign_string;
+    use crate::rest::money::Money;
+
+    #[test]
+    fn full_refund_has_no_amount() {
+        let payment = MonetixRefundPaymentModel::full("test");
+        let json = serde_json::to_string(&payment).unwrap();
+
+        assert_eq!(sign_string(&payment), "description:test");
+        assert!(serde_json::from_str::<MonetixRefundPaymentModel>(&json).unwrap().money.is_none());
+    }
+
+    #[test]
+    fn partial_refund() {
+        let mut payment = MonetixRefundPaymentModel::partial(Money::from_minor(100, Currency::USD), "test");
+        payment.merchant_refund_id = Some("refund_1".to_string());
+        let json = serde_json::to_string(&payment).unwrap();
+
+        assert_eq!(
+            sign_string(&payment),
+            "amount:100;currency:USD;description:test;merchant_refund_id:refund_1"
+        );
+        assert_eq!(
+            serde_json::from_str::<MonetixRefundPaymentModel>(&json).unwrap().money,
+            Some(Money::from_minor(100, Currency::USD))
+        );
+    }
+}
@@ -1,20 +1,39 @@
 use crate::rest::gate::endpoints::MonetixGateEndpoint;
-use crate::rest::errors::Error;
-use crate::rest::gate::models::{MonetixCreateInvoicePaymentRequest, MonetixCreateInvoicePaymentResponse, MonetixCustomerModel, MonetixGeneralModel, MonetixPaymentModel, MonetixReturnUrlModel};
+use crate::rest::errors::MonetixError;
+use crate::rest::gate::models::{CardOperationType, MonetixCreateInvoicePaymentRequest, MonetixCreateInvoicePaymentResponse, MonetixCustomerModel, MonetixGateRequest, MonetixGeneralModel, MonetixOperationResponse, MonetixPaymentModel, MonetixReturnUrlModel};
 use crate::rest::signer::{MonetixRequest, MonetixSigner};
+use crate::rest::transport::{MonetixHttpRequest, MonetixTransport, ReqwestTransport};
 use reqwest::header::{HeaderMap, HeaderValue};
 use serde::de::DeserializeOwned;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 use crate::rest::gate::payout::{MonetixCardModel, MonetixCardPayoutRequest, MonetixCustomerAccountModel, MonetixCustomerPayoutModel, MonetixPayoutPaymentModel, MonetixPayoutRequest};
+use crate::rest::gate::refund::{MonetixRefundPaymentModel, MonetixRefundRequest};
+use crate::rest::gate::card_sale::{MonetixCardDataModel, MonetixCardSaleCustomerModel, MonetixCardSalePaymentModel, MonetixCardSaleRequest, MonetixThreeDsResultRequest};
+use crate::rest::gate::recurring::{MonetixRecurringCancelRequest, MonetixRecurringModel, MonetixRecurringPaymentRequest, MonetixRecurringUpdateRequest, MonetixTokenSaleRequest};
+use crate::rest::gate::two_step::{MonetixCancelRequest, MonetixCaptureRequest, MonetixTwoStepPaymentModel};
+use crate::rest::gate::retry::{MonetixAttempt, RetryPolicy};
+use crate::rest::gate::validation::{Validate, ValidationErrors};
+use crate::rest::gate::payout_method::{PayoutAccountKind, PayoutMethod};
+use crate::rest::gate::colombia::{MonetixColombiaBankAccountModel, MonetixColombiaBankPayoutRequest, MonetixColombiaCustomerModel, MonetixNequiAccountModel, MonetixNequiPayoutRequest};
+use crate::rest::gate::pix::{MonetixPixAccountModel, MonetixPixCustomerModel, MonetixPixPaymentModel, MonetixPixPaymentRequest, MonetixPixPayoutRequest};
+use crate::rest::gate::spei::{MonetixSpeiAccountModel, MonetixSpeiCustomerModel, MonetixSpeiPayoutRequest};
+use crate::rest::gate::status::{MonetixPaymentStatusRequest, MonetixPaymentStatusResponse};
+
+pub const MONETIX_GATE_HOST: &str = "https://api.trxhost.com";
 
 #[derive(Clone)]
 pub struct MonetixGateRestClient {
     signer: MonetixSigner,
     host: String,
-    inner_client: reqwest::Client,
+    transport: Arc<dyn MonetixTransport>,
     project_id: u32,
     callback_url: Option<String>,
-    return_url: String,
+    return_url: MonetixReturnUrlModel,
+    timeout: Option<Duration>,
+    default_headers: HeaderMap,
+    retry_policy: RetryPolicy,
 }
 
 impl MonetixGateRestClient {
@@ -26,27 +45,51 @@ impl MonetixGateRestClient {
     ) -> Self {
         Self {
             signer: MonetixSigner::new(secret_key),
-            host: "https://api.trxhost.com".to_string(),
-            inner_client: reqwest::Client::new(),
+            host: MONETIX_GATE_HOST.to_string(),
+            transport: Arc::new(ReqwestTransport::default()),
             project_id,
-            return_url,
-            callback_url,            
+            return_url: MonetixReturnUrlModel {
+                success: Some(return_url.clone()),
+                decline: Some(return_url.clone()),
+                return_url: Some(return_url),
+            },
+            callback_url,
+            timeout: None,
+            default_headers: HeaderMap::new(),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    pub fn builder(project_id: u32, secret_key: impl Into<String>) -> MonetixGateRestClientBuilder {
+        MonetixGateRestClientBuilder::new(project_id, secret_key)
+    }
+
     pub async fn create_invoice_payment(
         &self,
         payment_id: impl Into<String>,
         customer_id: impl Into<String>,
         payment: MonetixPaymentModel,
-    ) -> Result<MonetixCreateInvoicePaymentResponse, Error> {
-        let mut request = MonetixCreateInvoicePaymentRequest {
-            general: MonetixGeneralModel {
-                project_id: self.project_id,
-                payment_id: payment_id.into(),
-                merchant_callback_url: self.callback_url.clone(),
-                signature: "".to_string(),
-            },
+    ) -> Result<MonetixCreateInvoicePaymentResponse, MonetixError> {
+        self.create_invoice_payment_with_operation_type(
+            payment_id,
+            customer_id,
+            payment,
+            CardOperationType::Sale,
+        )
+        .await
+    }
+
+    /// Creates an invoice. With `CardOperationType::Auth` the funds are only held
+    /// and must be captured or cancelled later.
+    pub async fn create_invoice_payment_with_operation_type(
+        &self,
+        payment_id: impl Into<String>,
+        customer_id: impl Into<String>,
+        payment: MonetixPaymentModel,
+        card_operation_type: CardOperationType,
+    ) -> Result<MonetixCreateInvoicePaymentResponse, MonetixError> {
+        let request = MonetixCreateInvoicePaymentRequest {
+            general: self.general(payment_id),
             customer: MonetixCustomerModel {
                 id: customer_id.into(),
                 //country: None,
@@ -64,22 +107,12 @@ impl MonetixGateRestClient {
                 //billing: None,
             },
             payment,
-            return_url: MonetixReturnUrlModel {
-                success: Some(self.return_url.clone()),
-                decline: Some(self.return_url.clone()),
-                return_url: Some(self.return_url.clone()),
-            },
-            card_operation_type: "sale".to_string(),
+            return_url: self.return_url.clone(),
+            card_operation_type,
             send_email: false,
         };
-        let sign = self.signer.generate_sign(&request)?;
-
-        request.general.signature = sign;
-
-        let endpoint = MonetixGateEndpoint::CreateInvoicePayment;
-        let result = self.post(endpoint, request).await;
 
-        result
+        self.post_validated(MonetixGateEndpoint::CreateInvoicePayment, request).await
     }
 
     pub async fn make_card_payout(
@@ -88,98 +121,418 @@ impl MonetixGateRestClient {
         customer: MonetixCustomerPayoutModel,
         card: MonetixCardModel,
         payment: MonetixPayoutPaymentModel,
-    ) -> Result<MonetixCreateInvoicePaymentResponse, Error> {
-        let mut request = MonetixCardPayoutRequest {
-            general: MonetixGeneralModel {
-                project_id: self.project_id,
-                payment_id: payment_id.into(),
-                merchant_callback_url: self.callback_url.clone(),
-                signature: "".to_string(),
-            },
+    ) -> Result<MonetixCreateInvoicePaymentResponse, MonetixError> {
+        let request = MonetixCardPayoutRequest {
+            general: self.general(payment_id),
             customer,
             payment,
             card
         };
-        let sign = self.signer.generate_sign(&request)?;
-
-        request.general.signature = sign;
+        Self::check_payout(&PayoutMethod::Card, &request, &request.customer)?;
 
-        let endpoint = MonetixGateEndpoint::MakePayout("card".into());
-        let result = self.post(endpoint, request).await;
-
-        result
+        self.post_signed(MonetixGateEndpoint::MakePayout(PayoutMethod::Card), request).await
     }
 
+    /// For card payouts `account.number` is sent as the card pan.
     pub async fn make_payout(
         &self,
         payment_id: impl Into<String>,
-        payment_method: impl Into<String>,
+        payout_method: PayoutMethod,
         customer: MonetixCustomerPayoutModel,
         account: MonetixCustomerAccountModel,
         payment: MonetixPayoutPaymentModel,
-    ) -> Result<MonetixCreateInvoicePaymentResponse, Error> {
-        let payment_method = payment_method.into();
-        let endpoint = MonetixGateEndpoint::MakePayout(payment_method.clone());
-
-        let result = if payment_method == "card" {
-            let mut request = MonetixCardPayoutRequest {
-                general: MonetixGeneralModel {
-                    project_id: self.project_id,
-                    payment_id: payment_id.into(),
-                    merchant_callback_url: self.callback_url.clone(),
-                    signature: "".to_string(),
-                },
+    ) -> Result<MonetixCreateInvoicePaymentResponse, MonetixError> {
+        let endpoint = MonetixGateEndpoint::MakePayout(payout_method.clone());
+
+        if payout_method.account_kind() == PayoutAccountKind::Card {
+            let request = MonetixCardPayoutRequest {
+                general: self.general(payment_id),
                 customer,
                 payment,
                 card: MonetixCardModel { pan: account.number },
             };
-            let sign = self.signer.generate_sign(&request)?;
-            request.general.signature = sign;
+            Self::check_payout(&payout_method, &request, &request.customer)?;
 
-            self.post(endpoint, request).await
+            self.post_signed(endpoint, request).await
         } else {
-            let mut request = MonetixPayoutRequest {
-                general: MonetixGeneralModel {
-                    project_id: self.project_id,
-                    payment_id: payment_id.into(),
-                    merchant_callback_url: self.callback_url.clone(),
-                    signature: "".to_string(),
-                },
+            let request = MonetixPayoutRequest {
+                general: self.general(payment_id),
                 customer,
                 account,
                 payment,
             };
-            let sign = self.signer.generate_sign(&request)?;
-            request.general.signature = sign;
+            Self::check_payout(&payout_method, &request, &request.customer)?;
+
+            self.post_signed(endpoint, request).await
+        }
+    }
+
+    /// Payout to a Mexican bank account, rejected locally if the CLABE, CURP or RFC is malformed.
+    pub async fn make_spei_payout(
+        &self,
+        payment_id: impl Into<String>,
+        customer: MonetixSpeiCustomerModel,
+        account: MonetixSpeiAccountModel,
+        payment: MonetixPayoutPaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixSpeiPayoutRequest {
+            general: self.general(payment_id),
+            customer,
+            account,
+            payment,
+        };
+
+        self.post_validated(MonetixGateEndpoint::MakePayout(PayoutMethod::Spei), request)
+            .await
+    }
+
+    /// Payout to a Colombian bank account.
+    pub async fn make_colombia_bank_payout(
+        &self,
+        payment_id: impl Into<String>,
+        customer: MonetixColombiaCustomerModel,
+        account: MonetixColombiaBankAccountModel,
+        payment: MonetixPayoutPaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixColombiaBankPayoutRequest {
+            general: self.general(payment_id),
+            customer,
+            account,
+            payment,
+        };
+
+        self.post_validated(MonetixGateEndpoint::MakePayout(PayoutMethod::ColombiaBankTransfer), request)
+            .await
+    }
+
+    /// Payout to a Nequi wallet by the phone number.
+    pub async fn make_nequi_payout(
+        &self,
+        payment_id: impl Into<String>,
+        customer: MonetixColombiaCustomerModel,
+        account: MonetixNequiAccountModel,
+        payment: MonetixPayoutPaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixNequiPayoutRequest {
+            general: self.general(payment_id),
+            customer,
+            account,
+            payment,
+        };
+
+        self.post_validated(MonetixGateEndpoint::MakePayout(PayoutMethod::Nequi), request)
+            .await
+    }
+
+    /// Payout to a PIX key.
+    pub async fn make_pix_payout(
+        &self,
+        payment_id: impl Into<String>,
+        customer: MonetixPixCustomerModel,
+        account: MonetixPixAccountModel,
+        payment: MonetixPayoutPaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixPixPayoutRequest {
+            general: self.general(payment_id),
+            customer,
+            account,
+            payment,
+        };
+
+        self.post_validated(MonetixGateEndpoint::MakePayout(PayoutMethod::Pix), request)
+            .await
+    }
+
+    /// Starts a PIX payment. The QR code payload comes in the callback, see
+    /// `MonetixCallbackModel::pix_qr_payload`.
+    pub async fn pix_payment(
+        &self,
+        payment_id: impl Into<String>,
+        customer: MonetixPixCustomerModel,
+        payment: MonetixPixPaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixPixPaymentRequest {
+            general: self.general(payment_id),
+            customer,
+            payment,
+            return_url: self.return_url.clone(),
+        };
+
+        self.post_validated(MonetixGateEndpoint::PixSale, request).await
+    }
+
+    pub async fn get_payment_status(
+        &self,
+        payment_id: impl Into<String>,
+    ) -> Result<MonetixPaymentStatusResponse, MonetixError> {
+        let request = MonetixPaymentStatusRequest {
+            general: MonetixGeneralModel {
+                merchant_callback_url: None,
+                ..self.general(payment_id)
+            },
+        };
+
+        self.post_signed(MonetixGateEndpoint::PaymentStatus, request).await
+    }
+
+    /// Refunds a card payment. Use `MonetixRefundPaymentModel::full` or
+    /// `MonetixRefundPaymentModel::partial` to choose the amount.
+    pub async fn refund(
+        &self,
+        payment_id: impl Into<String>,
+        payment: MonetixRefundPaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixRefundRequest {
+            general: self.general(payment_id),
+            payment,
+        };
+
+        self.post_validated(MonetixGateEndpoint::CardRefund, request).await
+    }
+
+    pub async fn capture(
+        &self,
+        payment_id: impl Into<String>,
+        payment: MonetixTwoStepPaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixCaptureRequest {
+            general: self.general(payment_id),
+            payment,
+        };
+
+        self.post_validated(MonetixGateEndpoint::CardCapture, request).await
+    }
 
-            self.post(endpoint, request).await
+    /// Voids an auth. Pass `None` as payment to release the whole held amount.
+    pub async fn cancel(
+        &self,
+        payment_id: impl Into<String>,
+        payment: Option<MonetixTwoStepPaymentModel>,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixCancelRequest {
+            general: self.general(payment_id),
+            payment,
+        };
+
+        self.post_validated(MonetixGateEndpoint::CardCancel, request).await
+    }
+
+    /// Host-to-host card sale. If the card requires 3‑D Secure, the callback contains
+    /// `acs` or `redirect_data` and the payment must be finished with `complete_3ds`.
+    pub async fn card_sale(
+        &self,
+        payment_id: impl Into<String>,
+        customer: MonetixCardSaleCustomerModel,
+        card: MonetixCardDataModel,
+        payment: MonetixCardSalePaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        self.card_sale_with_recurring(payment_id, customer, card, payment, None).await
+    }
+
+    /// Card sale that also registers a recurring when `recurring` is set.
+    pub async fn card_sale_with_recurring(
+        &self,
+        payment_id: impl Into<String>,
+        customer: MonetixCardSaleCustomerModel,
+        card: MonetixCardDataModel,
+        payment: MonetixCardSalePaymentModel,
+        recurring: Option<MonetixRecurringModel>,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixCardSaleRequest {
+            general: self.general(payment_id),
+            customer,
+            payment,
+            card,
+            return_url: self.return_url.clone(),
+            recurring,
+        };
+
+        self.post_validated(MonetixGateEndpoint::CardSale, request).await
+    }
+
+    pub async fn complete_3ds(
+        &self,
+        payment_id: impl Into<String>,
+        pa_res: impl Into<String>,
+        md: impl Into<String>,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixThreeDsResultRequest {
+            general: self.general(payment_id),
+            pares: pa_res.into(),
+            md: md.into(),
+        };
+
+        self.post_signed(MonetixGateEndpoint::CardThreeDsResult, request).await
+    }
+
+    /// Charges a recurring registered with a previous sale.
+    pub async fn recurring_payment(
+        &self,
+        payment_id: impl Into<String>,
+        recurring_id: u64,
+        customer: MonetixCardSaleCustomerModel,
+        payment: MonetixCardSalePaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixRecurringPaymentRequest {
+            general: self.general(payment_id),
+            customer,
+            payment,
+            recurring_id,
+        };
+
+        self.post_validated(MonetixGateEndpoint::CardRecurring, request).await
+    }
+
+    /// Charges a saved card by the token received in `MonetixAccountModel::token`.
+    pub async fn token_sale(
+        &self,
+        payment_id: impl Into<String>,
+        token: impl Into<String>,
+        customer: MonetixCardSaleCustomerModel,
+        payment: MonetixCardSalePaymentModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixTokenSaleRequest {
+            general: self.general(payment_id),
+            customer,
+            payment,
+            token: token.into(),
+        };
+
+        self.post_validated(MonetixGateEndpoint::CardTokenSale, request).await
+    }
+
+    pub async fn update_recurring(
+        &self,
+        payment_id: impl Into<String>,
+        recurring_id: u64,
+        recurring: MonetixRecurringModel,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixRecurringUpdateRequest {
+            general: self.general(payment_id),
+            recurring_id,
+            recurring,
+        };
+
+        self.post_validated(MonetixGateEndpoint::CardRecurringUpdate, request).await
+    }
+
+    pub async fn cancel_recurring(
+        &self,
+        payment_id: impl Into<String>,
+        recurring_id: u64,
+    ) -> Result<MonetixOperationResponse, MonetixError> {
+        let request = MonetixRecurringCancelRequest {
+            general: self.general(payment_id),
+            recurring_id,
         };
 
-        result
+        self.post_signed(MonetixGateEndpoint::CardRecurringCancel, request).await
+    }
+
+    fn general(&self, payment_id: impl Into<String>) -> MonetixGeneralModel {
+        MonetixGeneralModel {
+            project_id: self.project_id,
+            payment_id: payment_id.into(),
+            merchant_callback_url: self.callback_url.clone(),
+            signature: "".to_string(),
+        }
+    }
+
+    /// Checks the payout together with the customer fields required by the payout method.
+    fn check_payout(
+        payout_method: &PayoutMethod,
+        request: &impl Validate,
+        customer: &MonetixCustomerPayoutModel,
+    ) -> Result<(), MonetixError> {
+        let mut errors = ValidationErrors::new();
+        request.collect_errors(&mut errors);
+        payout_method.check_customer(customer, &mut errors);
+
+        errors.into_result().map_err(MonetixError::InvalidRequest)
+    }
+
+    /// Rejects the request locally if it is invalid, otherwise signs and posts it.
+    async fn post_validated<R: MonetixGateRequest + Validate, T: DeserializeOwned>(
+        &self,
+        endpoint: MonetixGateEndpoint,
+        request: R,
+    ) -> Result<T, MonetixError> {
+        request.validate().map_err(MonetixError::InvalidRequest)?;
+
+        self.post_signed(endpoint, request).await
+    }
+
+    /// Signs and posts the request without local checks.
+    async fn post_signed<R: MonetixGateRequest, T: DeserializeOwned>(
+        &self,
+        endpoint: MonetixGateEndpoint,
+        mut request: R,
+    ) -> Result<T, MonetixError> {
+        let sign = self.signer.generate_sign(&request).map_err(MonetixError::Signing)?;
+
+        request.general_mut().signature = sign;
+
+        self.post(endpoint, request).await
     }
 
     pub async fn post<R: MonetixRequest, T: DeserializeOwned>(
         &self,
         endpoint: MonetixGateEndpoint,
         request: R,
-    ) -> Result<T, Error> {
+    ) -> Result<T, MonetixError> {
         let url: String = format!("{}{}", self.host, String::from(&endpoint));
-        let headers = self.build_headers();
-        let client = &self.inner_client;
-        let request_json = serde_json::to_string(&request)?;        
-       
-        let response = client
-            .post(&url)
-            .body(request_json.clone())
-            .headers(headers)
-            .send()
-            .await;
+        let request_json = serde_json::to_string(&request)?;
+        let mut attempt = 1;
+
+        loop {
+            let result = self.send_once(&url, request_json.clone()).await;
 
-        crate::rest::response_handler::handle(response?, Some(request_json), &url).await
+            let error = result.as_ref().err();
+            let retry_in = match error {
+                Some(err)
+                    if attempt < self.retry_policy.max_attempts
+                        && self.retry_policy.should_retry(err) =>
+                {
+                    Some(self.retry_policy.backoff(attempt))
+                }
+                _ => None,
+            };
+
+            self.retry_policy.notify(&MonetixAttempt {
+                url: &url,
+                attempt,
+                error,
+                retry_in,
+            });
+
+            let Some(retry_in) = retry_in else {
+                return result;
+            };
+
+            tokio::time::sleep(retry_in).await;
+            attempt += 1;
+        }
+    }
+
+    async fn send_once<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        request_json: String,
+    ) -> Result<T, MonetixError> {
+        let request = MonetixHttpRequest {
+            method: http::Method::POST,
+            url: url.to_string(),
+            headers: self.build_headers(),
+            body: Some(request_json),
+            timeout: self.timeout,
+        };
+        let response = self.transport.send(request).await?;
+
+        crate::rest::response_handler::handle(response, url)
     }
 
     fn build_headers(&self) -> HeaderMap {
-        let mut custom_headers = HeaderMap::new();
+        let mut custom_headers = self.default_headers.clone();
 
         custom_headers.insert(
             "content-type",
@@ -199,3 +552,700 @@ impl MonetixGateRestClient {
         request
     }   
 }
+
+pub struct MonetixGateRestClientBuilder {
+    project_id: u32,
+    secret_key: String,
+    host: String,
+    transport: Option<Arc<dyn MonetixTransport>>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    default_headers: HeaderMap,
+    callback_url: Option<String>,
+    return_url: MonetixReturnUrlModel,
+    retry_policy: RetryPolicy,
+}
+
+impl MonetixGateRestClientBuilder {
+    pub fn new(project_id: u32, secret_key: impl Into<String>) -> Self {
+        Self {
+            project_id,
+            secret_key: secret_key.into(),
+            host: MONETIX_GATE_HOST.to_string(),
+            transport: None,
+            timeout: None,
+            connect_timeout: None,
+            default_headers: HeaderMap::new(),
+            callback_url: None,
+            return_url: MonetixReturnUrlModel {
+                success: None,
+                decline: None,
+                return_url: None,
+            },
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Base url of the gate without trailing slash, e.g. a sandbox or a local stub.
+    pub fn host(mut self, host: impl Into<String>) -> Self {
+        self.host = host.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Pre-configured client. Connect timeout is ignored in this case.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.transport = Some(Arc::new(ReqwestTransport::new(client)));
+        self
+    }
+
+    /// Custom transport, e.g. `MockTransport` in tests. Connect timeout is ignored in this case.
+    pub fn transport(mut self, transport: Arc<dyn MonetixTransport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Total timeout of a single request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Headers sent with every request. `content-type` is always `application/json`.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    pub fn callback_url(mut self, url: impl Into<String>) -> Self {
+        self.callback_url = Some(url.into());
+        self
+    }
+
+    pub fn success_url(mut self, url: impl Into<String>) -> Self {
+        self.return_url.success = Some(url.into());
+        self
+    }
+
+    pub fn decline_url(mut self, url: impl Into<String>) -> Self {
+        self.return_url.decline = Some(url.into());
+        self
+    }
+
+    pub fn return_url(mut self, url: impl Into<String>) -> Self {
+        self.return_url.return_url = Some(url.into());
+        self
+    }
+
+    /// Retries are disabled by default.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    pub fn build(self) -> Result<MonetixGateRestClient, MonetixError> {
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => {
+                let mut builder = reqwest::Client::builder();
+
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+
+                Arc::new(ReqwestTransport::new(builder.build()?))
+            }
+        };
+
+        Ok(MonetixGateRestClient {
+            signer: MonetixSigner::new(self.secret_key),
+            host: self.host,
+            transport,
+            project_id: self.project_id,
+            callback_url: self.callback_url,
+            return_url: self.return_url,
+            timeout: self.timeout,
+            default_headers: self.default_headers,
+            retry_policy: self.retry_policy,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::currencies::Currency;
+    use crate::rest::errors::MonetixError;
+    use crate::rest::gate::colombia::{ColombiaAccountType, ColombiaBank, MonetixColombiaBankAccountModel, MonetixNequiAccountModel};
+    use crate::rest::gate::card_sale::{MonetixCardDataModel, MonetixCardSaleCustomerModel, MonetixCardSalePaymentModel};
+    use crate::rest::gate::models::{CardOperationType, MonetixPaymentModel};
+    use crate::rest::gate::payout::{
+        MonetixCustomerAccountModel, MonetixCustomerIdentifyModel, MonetixCustomerPayoutModel,
+        MonetixPayoutPaymentModel,
+    };
+    use crate::rest::gate::payout_method::PayoutMethod;
+    use crate::rest::gate::recurring::{MonetixRecurringModel, MonetixRecurringType};
+    use crate::rest::gate::retry::RetryPolicy;
+    use crate::rest::gate::spei::MonetixSpeiAccountModel;
+    use crate::rest::gate::pix::{MonetixPixAccountModel, MonetixPixPaymentModel, PixKeyType};
+    use crate::rest::gate::test_fixtures::{colombia_customer, payout_payment, pix_customer, spei_customer};
+    use crate::rest::gate::rest_client::{MonetixGateRestClient, MONETIX_GATE_HOST};
+    use crate::rest::gate::two_step::MonetixTwoStepPaymentModel;
+    use crate::rest::money::Money;
+    use crate::rest::signer::MonetixSigner;
+    use crate::rest::transport::MockTransport;
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use reqwest::StatusCode;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    const STATUS_RESPONSE: &str = r#"{"project_id": 1000, "payment": {"id": "payment_id", "type": "purchase", "status": "success", "sum": {}}}"#;
+    const OPERATION_RESPONSE: &str = r#"{"status": "success", "request_id": "request_id", "project_id": 1000, "payment_id": "payment_id"}"#;
+
+    /// Client against a mock transport with one operation response queued.
+    fn client(transport: &Arc<MockTransport>) -> MonetixGateRestClient {
+        transport.push_response(StatusCode::OK, OPERATION_RESPONSE);
+
+        MonetixGateRestClient::builder(1000, "secret")
+            .host("http://stub")
+            .transport(transport.clone())
+            .build()
+            .unwrap()
+    }
+
+    /// Body of the only request sent, after checking its url and signature.
+    fn signed_body(transport: &MockTransport, url: &str) -> serde_json::Value {
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, url);
+
+        let body = requests[0].body.clone().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let signature = value["general"]["signature"].as_str().unwrap();
+        MonetixSigner::new("secret").verify(&body, signature).unwrap();
+
+        value
+    }
+
+    #[test]
+    fn builder_defaults() {
+        let client = MonetixGateRestClient::builder(1000, "secret").build().unwrap();
+
+        assert_eq!(client.host, MONETIX_GATE_HOST);
+        assert!(client.callback_url.is_none());
+        assert!(client.return_url.success.is_none());
+    }
+
+    #[test]
+    fn builder_custom() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-test", HeaderValue::from_static("1"));
+
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .host("http://localhost:8080/")
+            .timeout(Duration::from_secs(5))
+            .default_headers(headers)
+            .success_url("https://shop/success")
+            .decline_url("https://shop/decline")
+            .callback_url("https://shop/callback")
+            .build()
+            .unwrap();
+        let headers = client.build_headers();
+
+        assert_eq!(client.host, "http://localhost:8080");
+        assert_eq!(client.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(client.return_url.success.as_deref(), Some("https://shop/success"));
+        assert_eq!(client.return_url.decline.as_deref(), Some("https://shop/decline"));
+        assert!(client.return_url.return_url.is_none());
+        assert_eq!(headers.get("x-test").unwrap(), "1");
+        assert_eq!(headers.get("content-type").unwrap(), "application/json");
+    }
+
+    #[tokio::test]
+    async fn post_with_mock_transport() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::OK, STATUS_RESPONSE);
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .host("http://stub")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let response = client.get_payment_status("payment_id").await.unwrap();
+        let requests = transport.requests();
+
+        assert_eq!(response.payment.id, "payment_id");
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "http://stub/v2/payment/status");
+        assert!(requests[0].body.as_ref().unwrap().contains("\"signature\":\""));
+    }
+
+    #[tokio::test]
+    async fn retries_send_identical_body() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::SERVICE_UNAVAILABLE, "");
+        transport.push_response(StatusCode::BAD_GATEWAY, "");
+        transport.push_response(StatusCode::OK, STATUS_RESPONSE);
+        let attempts = Arc::new(AtomicU32::new(0));
+        let attempts_counter = attempts.clone();
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::exponential(3)
+        }
+        .on_attempt(move |_| {
+            attempts_counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .transport(transport.clone())
+            .retry_policy(policy)
+            .build()
+            .unwrap();
+
+        client.get_payment_status("payment_id").await.unwrap();
+        let requests = transport.requests();
+
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].body, requests[2].body);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn retries_custom_transport_errors() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_transport_error("connection reset", true);
+        transport.push_response(StatusCode::OK, STATUS_RESPONSE);
+        transport.push_transport_error("invalid certificate", false);
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::exponential(3)
+        };
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .transport(transport.clone())
+            .retry_policy(policy)
+            .build()
+            .unwrap();
+
+        client.get_payment_status("payment_id").await.unwrap();
+        assert_eq!(transport.requests().len(), 2);
+
+        let result = client.get_payment_status("payment_id").await;
+
+        assert!(matches!(result, Err(MonetixError::Transport { retryable: false, .. })));
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn no_retry_on_validation_error() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::BAD_REQUEST, r#"{"code": 3, "message": "Invalid"}"#);
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .transport(transport.clone())
+            .retry_policy(RetryPolicy::exponential(3))
+            .build()
+            .unwrap();
+
+        let result = client.get_payment_status("payment_id").await;
+
+        assert!(matches!(result, Err(MonetixError::Validation(_))));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn invalid_invoice_is_not_sent() {
+        let transport = Arc::new(MockTransport::new());
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let payment = MonetixPaymentModel {
+            money: Money::from_minor(0, Currency::USD),
+            description: None,
+            extra_param: None,
+            best_before: "".to_string(),
+            moto_type: 0,
+        };
+
+        let result = client.create_invoice_payment("payment_id", "", payment).await;
+
+        let Err(MonetixError::InvalidRequest(errors)) = result else {
+            panic!("expected invalid request, got {:?}", result);
+        };
+        assert_eq!(errors.to_string(), "customer.id: must not be empty; payment.amount: must be positive");
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn payout_with_missing_customer_fields_is_not_sent() {
+        let transport = Arc::new(MockTransport::new());
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let customer = MonetixCustomerPayoutModel {
+            id: "customer_id".to_string(),
+            ip_address: "127.0.0.1".to_string(),
+            first_name: "Juan".to_string(),
+            last_name: "Perez".to_string(),
+            identify: MonetixCustomerIdentifyModel {
+                doc_type: None,
+                doc_number: "doc_number".to_string(),
+            },
+            email: "".to_string(),
+        };
+        let account = MonetixCustomerAccountModel {
+            account_type: None,
+            bank_id: None,
+            number: "".to_string(),
+        };
+        let payment = MonetixPayoutPaymentModel {
+            money: Money::from_minor(1000, Currency::MXN),
+        };
+
+        let result = client
+            .make_payout("payment_id", PayoutMethod::Spei, customer, account, payment)
+            .await;
+
+        let Err(MonetixError::InvalidRequest(errors)) = result else {
+            panic!("expected invalid request, got {:?}", result);
+        };
+        assert_eq!(
+            errors.to_string(),
+            "customer.email: must be a valid email; account.number: must not be empty; customer.identify.doc_type: is required for this payout method"
+        );
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn auth_invoice() {
+        let transport = Arc::new(MockTransport::new());
+        let payment = MonetixPaymentModel {
+            money: Money::from_minor(1000, Currency::USD),
+            description: None,
+            extra_param: None,
+            best_before: "".to_string(),
+            moto_type: 0,
+        };
+
+        client(&transport)
+            .create_invoice_payment_with_operation_type("payment_id", "customer_id", payment, CardOperationType::Auth)
+            .await
+            .unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/invoice/create");
+
+        assert_eq!(body["card_operation_type"], "auth");
+    }
+
+    #[tokio::test]
+    async fn capture() {
+        let transport = Arc::new(MockTransport::new());
+        let payment = MonetixTwoStepPaymentModel {
+            money: Money::from_minor(500, Currency::USD),
+        };
+
+        client(&transport).capture("payment_id", payment).await.unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/card/capture");
+
+        assert_eq!(body["general"]["payment_id"], "payment_id");
+        assert_eq!(body["payment"], serde_json::json!({"amount": 500, "currency": "USD"}));
+    }
+
+    #[tokio::test]
+    async fn invalid_capture_is_not_sent() {
+        let transport = Arc::new(MockTransport::new());
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let payment = MonetixTwoStepPaymentModel {
+            money: Money::from_minor(0, Currency::USD),
+        };
+
+        let result = client.capture("", payment).await;
+
+        let Err(MonetixError::InvalidRequest(errors)) = result else {
+            panic!("expected invalid request, got {:?}", result);
+        };
+        assert_eq!(
+            errors.to_string(),
+            "general.payment_id: must not be empty; payment.amount: must be positive"
+        );
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn cancel() {
+        let transport = Arc::new(MockTransport::new());
+
+        client(&transport).cancel("payment_id", None).await.unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/card/cancel");
+
+        assert_eq!(body["general"]["payment_id"], "payment_id");
+        assert!(body.get("payment").is_none());
+
+        let transport = Arc::new(MockTransport::new());
+        let payment = MonetixTwoStepPaymentModel {
+            money: Money::from_minor(300, Currency::USD),
+        };
+
+        client(&transport).cancel("payment_id", Some(payment)).await.unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/card/cancel");
+
+        assert_eq!(body["payment"]["amount"], 300);
+    }
+
+    fn card_sale_customer() -> MonetixCardSaleCustomerModel {
+        MonetixCardSaleCustomerModel {
+            id: "customer_id".to_string(),
+            ip_address: "127.0.0.1".to_string(),
+        }
+    }
+
+    fn card_sale_payment() -> MonetixCardSalePaymentModel {
+        MonetixCardSalePaymentModel {
+            money: Money::from_minor(1000, Currency::USD),
+            description: None,
+        }
+    }
+
+    fn card() -> MonetixCardDataModel {
+        MonetixCardDataModel {
+            pan: "4000000000000077".to_string(),
+            year: 2030,
+            month: 1,
+            card_holder: "TEST TEST".to_string(),
+            cvv: "123".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn card_sale() {
+        let transport = Arc::new(MockTransport::new());
+
+        client(&transport)
+            .card_sale("payment_id", card_sale_customer(), card(), card_sale_payment())
+            .await
+            .unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/card/sale");
+
+        assert_eq!(body["card"]["pan"], "4000000000000077");
+        assert_eq!(body["payment"]["amount"], 1000);
+    }
+
+    #[tokio::test]
+    async fn invalid_card_is_not_sent() {
+        let transport = Arc::new(MockTransport::new());
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let mut card = card();
+        card.pan = "4000000000000078".to_string();
+        card.month = 0;
+
+        let result = client
+            .card_sale("payment_id", card_sale_customer(), card, card_sale_payment())
+            .await;
+
+        let Err(MonetixError::InvalidRequest(errors)) = result else {
+            panic!("expected invalid request, got {:?}", result);
+        };
+        assert_eq!(
+            errors.to_string(),
+            "card.pan: must be a valid card number; card.month: must be from 1 to 12"
+        );
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn complete_3ds() {
+        let transport = Arc::new(MockTransport::new());
+
+        client(&transport).complete_3ds("payment_id", "pa_res", "md").await.unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/card/3ds_result");
+
+        assert_eq!(body["general"]["payment_id"], "payment_id");
+        assert_eq!(body["pares"], "pa_res");
+        assert_eq!(body["md"], "md");
+    }
+
+    #[tokio::test]
+    async fn recurring_payment() {
+        let transport = Arc::new(MockTransport::new());
+
+        client(&transport)
+            .recurring_payment("payment_id", 42, card_sale_customer(), card_sale_payment())
+            .await
+            .unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/card/recurring");
+
+        assert_eq!(body["recurring_id"], 42);
+        assert_eq!(body["payment"]["amount"], 1000);
+    }
+
+    #[tokio::test]
+    async fn token_sale() {
+        let transport = Arc::new(MockTransport::new());
+
+        client(&transport)
+            .token_sale("payment_id", "card_token", card_sale_customer(), card_sale_payment())
+            .await
+            .unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/card/sale/token");
+
+        assert_eq!(body["token"], "card_token");
+        assert_eq!(body["customer"]["id"], "customer_id");
+    }
+
+    #[tokio::test]
+    async fn invalid_recurring_payment_is_not_sent() {
+        let transport = Arc::new(MockTransport::new());
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let mut payment = card_sale_payment();
+        payment.money = Money::from_minor(-100, Currency::USD);
+
+        let result = client
+            .recurring_payment("payment_id", 42, card_sale_customer(), payment)
+            .await;
+
+        let Err(MonetixError::InvalidRequest(errors)) = result else {
+            panic!("expected invalid request, got {:?}", result);
+        };
+        assert_eq!(errors.to_string(), "payment.amount: must be positive");
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_token_sale_is_not_sent() {
+        let transport = Arc::new(MockTransport::new());
+        let client = MonetixGateRestClient::builder(1000, "secret")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let mut customer = card_sale_customer();
+        customer.ip_address = "localhost".to_string();
+
+        let result = client.token_sale("payment_id", "", customer, card_sale_payment()).await;
+
+        let Err(MonetixError::InvalidRequest(errors)) = result else {
+            panic!("expected invalid request, got {:?}", result);
+        };
+        assert_eq!(
+            errors.to_string(),
+            "customer.ip_address: must be a valid IPv4 or IPv6 address; token: must not be empty"
+        );
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn update_recurring() {
+        let transport = Arc::new(MockTransport::new());
+        let recurring = MonetixRecurringModel {
+            register: true,
+            recurring_type: MonetixRecurringType::Regular,
+            expiry_day: None,
+            expiry_month: None,
+            expiry_year: None,
+            period: Some("M".to_string()),
+            interval: Some(1),
+            time: None,
+            start_date: None,
+            amount: Some(1000),
+        };
+
+        client(&transport).update_recurring("payment_id", 42, recurring).await.unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/card/recurring/update");
+
+        assert_eq!(body["recurring_id"], 42);
+        assert_eq!(body["recurring"]["type"], "R");
+        assert_eq!(body["recurring"]["period"], "M");
+    }
+
+    #[tokio::test]
+    async fn cancel_recurring() {
+        let transport = Arc::new(MockTransport::new());
+
+        client(&transport).cancel_recurring("payment_id", 42).await.unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/card/recurring/cancel");
+
+        assert_eq!(body["recurring_id"], 42);
+    }
+
+    #[tokio::test]
+    async fn bank_transfer_payouts() {
+        let transport = Arc::new(MockTransport::new());
+        let account = MonetixSpeiAccountModel::clabe("002180000118359710");
+
+        client(&transport)
+            .make_spei_payout("payment_id", spei_customer(), account, payout_payment(1000, Currency::MXN))
+            .await
+            .unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/bank-transfer/spei/payout");
+
+        assert_eq!(body["account"]["number"], "002180000118359710");
+
+        let transport = Arc::new(MockTransport::new());
+        let account = MonetixColombiaBankAccountModel {
+            account_type: ColombiaAccountType::Savings,
+            number: "12345678901".to_string(),
+            bank: ColombiaBank::Bancolombia,
+        };
+
+        client(&transport)
+            .make_colombia_bank_payout("payment_id", colombia_customer(), account, payout_payment(5000000, Currency::COP))
+            .await
+            .unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/bank-transfer/colombia/payout");
+
+        assert_eq!(body["account"]["bank_id"], 1007);
+    }
+
+    #[tokio::test]
+    async fn invalid_nequi_payout_is_not_sent() {
+        let transport = Arc::new(MockTransport::new());
+        let account = MonetixNequiAccountModel {
+            number: "+573001234567".to_string(),
+        };
+
+        let result = client(&transport)
+            .make_nequi_payout("payment_id", colombia_customer(), account, payout_payment(5000000, Currency::COP))
+            .await;
+
+        assert!(matches!(result, Err(MonetixError::InvalidRequest(_))));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn pix() {
+        let transport = Arc::new(MockTransport::new());
+        let account = MonetixPixAccountModel {
+            key_type: PixKeyType::Email,
+            number: "joao@example.com.br".to_string(),
+        };
+
+        client(&transport)
+            .make_pix_payout("payment_id", pix_customer(), account, payout_payment(1000, Currency::BRL))
+            .await
+            .unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/bank-transfer/pix/payout");
+
+        assert_eq!(body["account"]["type"], "EMAIL");
+
+        let transport = Arc::new(MockTransport::new());
+        let payment = MonetixPixPaymentModel {
+            money: Money::from_minor(1000, Currency::BRL),
+            description: None,
+        };
+
+        client(&transport).pix_payment("payment_id", pix_customer(), payment).await.unwrap();
+        let body = signed_body(&transport, "http://stub/v2/payment/bank-transfer/pix/sale");
+
+        assert_eq!(body["customer"]["identify"]["doc_number"], "52998224725");
+    }
+}
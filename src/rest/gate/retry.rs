@@ -0,0 +1,164 @@
+use crate::rest::errors::MonetixError;
+use rand::Rng;
+use reqwest::StatusCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Information about a single attempt to send a request.
+#[derive(Debug)]
+pub struct MonetixAttempt<'a> {
+    pub url: &'a str,
+    /// Attempt number starting from 1
+    pub attempt: u32,
+    /// `None` if the attempt succeeded
+    pub error: Option<&'a MonetixError>,
+    /// Delay before the next attempt, `None` if there won't be one
+    pub retry_in: Option<Duration>,
+}
+
+pub type MonetixAttemptListener = Arc<dyn Fn(&MonetixAttempt) + Send + Sync>;
+
+/// Controls how gate requests are resent on failures.
+/// Every attempt sends exactly the same signed body, so Monetix treats
+/// the attempts as one payment identified by `payment_id`.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Total number of attempts including the first one
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Randomizes each delay between half and full backoff
+    pub jitter: bool,
+    pub retryable_statuses: Vec<StatusCode>,
+    /// Retry on connection errors and timeouts
+    pub retry_on_transport: bool,
+    /// Called after every attempt, see `RetryPolicy::on_attempt`
+    pub on_attempt: Option<MonetixAttemptListener>,
+}
+
+impl Default for RetryPolicy {
+    /// Single attempt without retries.
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            jitter: true,
+            retryable_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_on_transport: true,
+            on_attempt: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn exponential(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Default::default()
+        }
+    }
+
+    /// Called after every attempt, e.g. for logging.
+    pub fn on_attempt(mut self, listener: impl Fn(&MonetixAttempt) + Send + Sync + 'static) -> Self {
+        self.on_attempt = Some(Arc::new(listener));
+        self
+    }
+
+    pub fn should_retry(&self, error: &MonetixError) -> bool {
+        match error {
+            MonetixError::Transport { .. } => self.retry_on_transport && error.is_retryable(),
+            MonetixError::Http { status, .. } => self.retryable_statuses.contains(status),
+            _ => false,
+        }
+    }
+
+    /// Delay before the attempt following the given one (starting from 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self.initial_backoff.saturating_mul(factor).min(self.max_backoff);
+
+        if self.jitter {
+            let millis = backoff.as_millis() as u64;
+            Duration::from_millis(rand::thread_rng().gen_range(millis / 2..=millis))
+        } else {
+            backoff
+        }
+    }
+
+    pub(crate) fn notify(&self, attempt: &MonetixAttempt) {
+        if let Some(listener) = &self.on_attempt {
+            listener(attempt);
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("jitter", &self.jitter)
+            .field("retryable_statuses", &self.retryable_statuses)
+            .field("retry_on_transport", &self.retry_on_transport)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::errors::MonetixError;
+    use crate::rest::gate::retry::RetryPolicy;
+    use reqwest::StatusCode;
+    use std::time::Duration;
+
+    fn http_error(status: StatusCode) -> MonetixError {
+        MonetixError::Http {
+            status,
+            url: "url".to_string(),
+            body: "".to_string(),
+        }
+    }
+
+    #[test]
+    fn backoff_without_jitter() {
+        let policy = RetryPolicy {
+            jitter: false,
+            ..RetryPolicy::exponential(5)
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff(3), Duration::from_millis(800));
+        assert_eq!(policy.backoff(10), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn backoff_with_jitter() {
+        let policy = RetryPolicy::exponential(5);
+
+        for _ in 0..100 {
+            let backoff = policy.backoff(2);
+            assert!(backoff >= Duration::from_millis(200) && backoff <= Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn should_retry() {
+        let policy = RetryPolicy {
+            retryable_statuses: vec![StatusCode::SERVICE_UNAVAILABLE],
+            ..RetryPolicy::exponential(3)
+        };
+
+        assert!(policy.should_retry(&http_error(StatusCode::SERVICE_UNAVAILABLE)));
+        assert!(!policy.should_retry(&http_error(StatusCode::INTERNAL_SERVER_ERROR)));
+        assert!(!policy.should_retry(&MonetixError::Signing("err".to_string())));
+    }
+}
@@ -0,0 +1,368 @@
+use crate::rest::currencies::Currency;
+use crate::rest::gate::models::{MonetixGateRequest, MonetixGeneralModel};
+use crate::rest::gate::payout::MonetixPayoutPaymentModel;
+use crate::rest::gate::validation::{is_valid_email, is_valid_ip, Validate, ValidationErrors};
+use crate::rest::signer::MonetixRequest;
+use serde_derive::{Deserialize, Serialize};
+
+impl MonetixRequest for MonetixSpeiPayoutRequest {}
+
+impl MonetixGateRequest for MonetixSpeiPayoutRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Payout to a Mexican bank account via SPEI, amounts are in MXN only.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixSpeiPayoutRequest {
+    pub general: MonetixGeneralModel,
+    pub customer: MonetixSpeiCustomerModel,
+    pub account: MonetixSpeiAccountModel,
+    pub payment: MonetixPayoutPaymentModel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixSpeiCustomerModel {
+    pub id: String,
+    pub ip_address: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub identify: MonetixSpeiIdentifyModel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SpeiDocType {
+    /// Clave Única de Registro de Población, personal id of Mexican citizens and residents
+    Curp,
+    /// Registro Federal de Contribuyentes, Mexican taxpayer id
+    Rfc,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixSpeiIdentifyModel {
+    pub doc_type: SpeiDocType,
+    pub doc_number: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SpeiAccountType {
+    /// 18-digit interbank account number
+    Clabe,
+    /// 16-digit debit card number
+    Debit,
+    /// 10-digit phone number linked to the account
+    Phone,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixSpeiAccountModel {
+    #[serde(rename = "type")]
+    pub account_type: SpeiAccountType,
+    pub number: String,
+    /// Monetix bank id, required for debit cards and phone numbers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_id: Option<i32>,
+}
+
+impl MonetixSpeiAccountModel {
+    pub fn clabe(number: impl Into<String>) -> Self {
+        Self {
+            account_type: SpeiAccountType::Clabe,
+            number: number.into(),
+            bank_id: None,
+        }
+    }
+
+    /// Bank of a CLABE account, `None` for other account types or banks missing from `SpeiBank`.
+    pub fn bank(&self) -> Option<SpeiBank> {
+        match self.account_type {
+            SpeiAccountType::Clabe => SpeiBank::from_clabe(&self.number),
+            _ => None,
+        }
+    }
+}
+
+bank_codes! {
+    /// Banks of the SPEI network by the three-digit ABM code used as the CLABE prefix.
+    pub enum SpeiBank {
+        Banamex => 2, "BANAMEX";
+        Bancomext => 6, "BANCOMEXT";
+        Banobras => 9, "BANOBRAS";
+        BbvaMexico => 12, "BBVA MEXICO";
+        Santander => 14, "SANTANDER";
+        Banjercito => 19, "BANJERCITO";
+        Hsbc => 21, "HSBC";
+        Bajio => 30, "BAJIO";
+        Inbursa => 36, "INBURSA";
+        Mifel => 42, "MIFEL";
+        Scotiabank => 44, "SCOTIABANK";
+        Banregio => 58, "BANREGIO";
+        Invex => 59, "INVEX";
+        Bansi => 60, "BANSI";
+        Afirme => 62, "AFIRME";
+        Banorte => 72, "BANORTE";
+        BankOfAmerica => 106, "BANK OF AMERICA";
+        VePorMas => 113, "VE POR MAS";
+        Azteca => 127, "AZTECA";
+        Autofin => 128, "AUTOFIN";
+        Compartamos => 130, "COMPARTAMOS";
+        Multiva => 132, "MULTIVA";
+        Actinver => 133, "ACTINVER";
+        Intercam => 136, "INTERCAM BANCO";
+        BanCoppel => 137, "BANCOPPEL";
+        AbcCapital => 138, "ABC CAPITAL";
+        Consubanco => 140, "CONSUBANCO";
+        CiBanco => 143, "CIBANCO";
+        Bbase => 145, "BBASE";
+        PagaTodo => 148, "PAGATODO";
+        Bancrea => 152, "BANCREA";
+        Sabadell => 156, "SABADELL";
+        Bienestar => 166, "BANCO DEL BIENESTAR";
+        Monex => 600, "MONEX";
+        Gbm => 601, "GBM";
+        NuMexico => 638, "NU MEXICO";
+        Stp => 646, "STP";
+        MercadoPago => 722, "MERCADO PAGO W";
+    }
+}
+
+impl SpeiBank {
+    /// Bank by the first three digits of the CLABE.
+    pub fn from_clabe(clabe: &str) -> Option<Self> {
+        let code = clabe.get(..3)?.parse().ok()?;
+
+        SpeiBank::from_code(code)
+    }
+}
+
+fn is_digits(value: &str, len: usize) -> bool {
+    value.len() == len && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_letters(value: &[u8]) -> bool {
+    value.iter().all(|b| b.is_ascii_uppercase())
+}
+
+/// `YYMMDD` with a valid month and day.
+fn is_date(value: &[u8]) -> bool {
+    let Ok(value) = std::str::from_utf8(value) else {
+        return false;
+    };
+
+    if !is_digits(value, 6) {
+        return false;
+    }
+
+    let month: u32 = value[2..4].parse().unwrap_or(0);
+    let day: u32 = value[4..6].parse().unwrap_or(0);
+
+    (1..=12).contains(&month) && (1..=31).contains(&day)
+}
+
+/// 18 digits: bank code, branch, account and a control digit over the first 17 digits
+/// with the repeating weights 3, 7, 1.
+pub fn is_valid_clabe(value: &str) -> bool {
+    if !is_digits(value, 18) {
+        return false;
+    }
+
+    let digits: Vec<u32> = value.bytes().map(|b| (b - b'0') as u32).collect();
+    let sum: u32 = digits[..17]
+        .iter()
+        .zip([3, 7, 1].iter().cycle())
+        .map(|(digit, weight)| digit * weight % 10)
+        .sum();
+
+    (10 - sum % 10) % 10 == digits[17]
+}
+
+/// 18 characters: 4 letters, birth date, sex, state, 3 consonants, homoclave and a check digit.
+pub fn is_valid_curp(value: &str) -> bool {
+    let bytes = value.as_bytes();
+
+    if bytes.len() != 18 {
+        return false;
+    }
+
+    let is_format_valid = is_letters(&bytes[..4])
+        && is_date(&bytes[4..10])
+        && matches!(bytes[10], b'H' | b'M' | b'X')
+        && is_letters(&bytes[11..16])
+        && bytes[16].is_ascii_alphanumeric()
+        && bytes[17].is_ascii_digit();
+
+    if !is_format_valid {
+        return false;
+    }
+
+    // position in 0-9, A-N, Ñ, O-Z; Ñ itself is replaced with X in CURPs
+    let char_value = |b: u8| match b {
+        b'0'..=b'9' => (b - b'0') as usize,
+        b'A'..=b'N' => (b - b'A') as usize + 10,
+        _ => (b - b'A') as usize + 11,
+    };
+    let sum: usize = bytes[..17]
+        .iter()
+        .enumerate()
+        .map(|(position, b)| char_value(*b) * (18 - position))
+        .sum();
+
+    (10 - sum % 10) % 10 == (bytes[17] - b'0') as usize
+}
+
+/// 13 characters for individuals or 12 for companies: name letters, registration date and homoclave.
+pub fn is_valid_rfc(value: &str) -> bool {
+    let letters = match value.chars().count() {
+        13 => 4,
+        12 => 3,
+        _ => return false,
+    };
+    let chars: Vec<char> = value.chars().collect();
+    let date: String = chars[letters..letters + 6].iter().collect();
+
+    chars[..letters].iter().all(|c| c.is_ascii_uppercase() || *c == 'Ñ' || *c == '&')
+        && is_date(date.as_bytes())
+        && chars[letters + 6..].iter().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+impl Validate for MonetixSpeiPayoutRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("customer", &self.customer);
+        errors.nested("account", &self.account);
+        errors.nested("payment", &self.payment);
+        errors.check(
+            self.payment.money.currency() == Currency::MXN,
+            "payment.currency",
+            "must be MXN",
+        );
+    }
+}
+
+impl Validate for MonetixSpeiCustomerModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.not_empty("id", &self.id);
+        errors.check(is_valid_ip(&self.ip_address), "ip_address", "must be a valid IPv4 or IPv6 address");
+        errors.not_empty("first_name", &self.first_name);
+        errors.not_empty("last_name", &self.last_name);
+        errors.check(is_valid_email(&self.email), "email", "must be a valid email");
+        errors.nested("identify", &self.identify);
+    }
+}
+
+impl Validate for MonetixSpeiIdentifyModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        match self.doc_type {
+            SpeiDocType::Curp => errors.check(is_valid_curp(&self.doc_number), "doc_number", "must be a valid CURP"),
+            SpeiDocType::Rfc => errors.check(is_valid_rfc(&self.doc_number), "doc_number", "must be a valid RFC"),
+        }
+    }
+}
+
+impl Validate for MonetixSpeiAccountModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        match self.account_type {
+            SpeiAccountType::Clabe => {
+                errors.check(is_valid_clabe(&self.number), "number", "must be a valid 18-digit CLABE")
+            }
+            SpeiAccountType::Debit => errors.check(is_digits(&self.number, 16), "number", "must have 16 digits"),
+            SpeiAccountType::Phone => errors.check(is_digits(&self.number, 10), "number", "must have 10 digits"),
+        }
+
+        if self.account_type != SpeiAccountType::Clabe {
+            errors.check(self.bank_id.is_some(), "bank_id", "is required for debit cards and phone numbers");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::currencies::Currency;
+    use crate::rest::gate::spei::*;
+    use crate::rest::gate::test_fixtures::{general, payout_payment, sign_string, spei_customer};
+    use crate::rest::gate::validation::Validate;
+    use crate::rest::money::Money;
+
+    fn request() -> MonetixSpeiPayoutRequest {
+        MonetixSpeiPayoutRequest {
+            general: general(),
+            customer: spei_customer(),
+            account: MonetixSpeiAccountModel::clabe("002180000118359710"),
+            payment: payout_payment(1000, Currency::MXN),
+        }
+    }
+
+    #[test]
+    fn clabe() {
+        assert!(is_valid_clabe("002180000118359710"));
+        assert!(is_valid_clabe("646180000000000012"));
+        assert!(!is_valid_clabe("002180000118359711"));
+        assert!(!is_valid_clabe("00218000011835971"));
+        assert!(!is_valid_clabe("00218000011835971a"));
+    }
+
+    #[test]
+    fn bank_from_clabe() {
+        assert_eq!(SpeiBank::from_clabe("002180000118359710"), Some(SpeiBank::Banamex));
+        assert_eq!(SpeiBank::from_clabe("646180000000000012"), Some(SpeiBank::Stp));
+        assert_eq!(SpeiBank::from_clabe("999180000000000012"), None);
+        assert_eq!(SpeiBank::BbvaMexico.code(), 12);
+        assert_eq!(MonetixSpeiAccountModel::clabe("012180001234567899").bank(), Some(SpeiBank::BbvaMexico));
+    }
+
+    #[test]
+    fn curp() {
+        assert!(is_valid_curp("BADD110313HCMLNS06"));
+        assert!(is_valid_curp("GODE561231HDFRRN00"));
+        assert!(!is_valid_curp("BADD110313HCMLNS07"));
+        assert!(!is_valid_curp("BADD111313HCMLNS06"));
+        assert!(!is_valid_curp("BADD110313HCMLNS0"));
+    }
+
+    #[test]
+    fn rfc() {
+        assert!(is_valid_rfc("GODE561231GR8"));
+        assert!(is_valid_rfc("ABC680524P76"));
+        assert!(is_valid_rfc("ÑAÑO800101AB1"));
+        assert!(!is_valid_rfc("GODE561331GR8"));
+        assert!(!is_valid_rfc("GOD3561231GR8"));
+        assert!(!is_valid_rfc("GODE561231GR"));
+    }
+
+    #[test]
+    fn valid_request() {
+        let request = request();
+        let sign_string = sign_string(&request);
+
+        assert!(request.validate().is_ok());
+        assert!(sign_string.contains("account:number:002180000118359710;account:type:CLABE"));
+        assert!(sign_string.contains("customer:identify:doc_type:CURP"));
+    }
+
+    #[test]
+    fn invalid_request() {
+        let mut request = request();
+        request.customer.identify = MonetixSpeiIdentifyModel {
+            doc_type: SpeiDocType::Rfc,
+            doc_number: "BADD110313HCMLNS06".to_string(),
+        };
+        request.account = MonetixSpeiAccountModel {
+            account_type: SpeiAccountType::Phone,
+            number: "5512345678".to_string(),
+            bank_id: None,
+        };
+        request.payment.money = Money::from_minor(1000, Currency::USD);
+
+        let errors = request.validate().unwrap_err();
+        let fields: Vec<_> = errors.errors().iter().map(|error| error.field.as_str()).collect();
+
+        assert_eq!(
+            fields,
+            vec!["customer.identify.doc_number", "account.bank_id", "payment.currency"]
+        );
+    }
+}
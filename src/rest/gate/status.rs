@@ -0,0 +1,73 @@
+use crate::rest::callback::{
+    MonetixAccountModel, MonetixAcsModel, MonetixCallbackCustomerModel,
+    MonetixCallbackPaymentModel, MonetixErrorItemModel, MonetixOperationModel,
+    MonetixRedirectDataModel,
+};
+use crate::rest::gate::models::{MonetixGateRequest, MonetixGeneralModel};
+use crate::rest::signer::MonetixRequest;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixPaymentStatusRequest {
+    pub general: MonetixGeneralModel,
+}
+
+impl MonetixRequest for MonetixPaymentStatusRequest {}
+
+impl MonetixGateRequest for MonetixPaymentStatusRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Current state of the payment with the same data as in callbacks.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixPaymentStatusResponse {
+    pub project_id: u64,
+    pub payment: MonetixCallbackPaymentModel,
+    /// All operations made within the payment, oldest first.
+    pub operations: Option<Vec<MonetixOperationModel>>,
+    pub errors: Option<Vec<MonetixErrorItemModel>>,
+    pub account: Option<MonetixAccountModel>,
+    pub customer: Option<MonetixCallbackCustomerModel>,
+    pub acs: Option<MonetixAcsModel>,
+    pub redirect_data: Option<MonetixRedirectDataModel>,
+    pub signature: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::gate::status::MonetixPaymentStatusResponse;
+    use crate::rest::status::{OperationType, PaymentStatus};
+
+    #[test]
+    fn deserialize_response() {
+        let json = r#"
+{
+    "project_id": 1000,
+    "payment": {
+        "id": "payment_id",
+        "type": "purchase",
+        "status": "success",
+        "date": "2024-03-11T18:21:23+0000",
+        "method": "card",
+        "sum": {"amount": 1000, "currency": "USD"}
+    },
+    "operations": [{
+        "id": 5,
+        "type": "sale",
+        "status": "success",
+        "request_id": "request_id",
+        "sum_initial": {"amount": 1000, "currency": "USD"}
+    }],
+    "account": {"number": "400000******0077"},
+    "errors": []
+}"#;
+        let response: MonetixPaymentStatusResponse = serde_json::from_str(json).unwrap();
+        let operations = response.operations.unwrap();
+
+        assert_eq!(response.payment.status, PaymentStatus::Success);
+        assert_eq!(operations[0].op_type, OperationType::Sale);
+        assert_eq!(response.account.unwrap().number, "400000******0077");
+    }
+}
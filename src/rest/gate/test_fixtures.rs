@@ -0,0 +1,73 @@
+//! Request parts shared by the tests of the gate models.
+
+use crate::rest::currencies::Currency;
+use crate::rest::gate::colombia::{ColombiaDocType, MonetixColombiaCustomerModel, MonetixColombiaIdentifyModel};
+use crate::rest::gate::models::MonetixGeneralModel;
+use crate::rest::gate::payout::MonetixPayoutPaymentModel;
+use crate::rest::gate::pix::{MonetixPixCustomerModel, MonetixPixIdentifyModel};
+use crate::rest::gate::spei::{MonetixSpeiCustomerModel, MonetixSpeiIdentifyModel, SpeiDocType};
+use crate::rest::money::Money;
+use crate::rest::signer::MonetixSigner;
+use serde::Serialize;
+
+pub fn general() -> MonetixGeneralModel {
+    MonetixGeneralModel {
+        project_id: 1000,
+        payment_id: "payment_id".to_string(),
+        merchant_callback_url: None,
+        signature: "".to_string(),
+    }
+}
+
+pub fn payout_payment(minor: i64, currency: Currency) -> MonetixPayoutPaymentModel {
+    MonetixPayoutPaymentModel {
+        money: Money::from_minor(minor, currency),
+    }
+}
+
+pub fn spei_customer() -> MonetixSpeiCustomerModel {
+    MonetixSpeiCustomerModel {
+        id: "customer_id".to_string(),
+        ip_address: "127.0.0.1".to_string(),
+        first_name: "Diego".to_string(),
+        last_name: "Barrera".to_string(),
+        email: "diego@example.mx".to_string(),
+        identify: MonetixSpeiIdentifyModel {
+            doc_type: SpeiDocType::Curp,
+            doc_number: "BADD110313HCMLNS06".to_string(),
+        },
+    }
+}
+
+pub fn colombia_customer() -> MonetixColombiaCustomerModel {
+    MonetixColombiaCustomerModel {
+        id: "customer_id".to_string(),
+        ip_address: "127.0.0.1".to_string(),
+        first_name: "Camila".to_string(),
+        last_name: "Rojas".to_string(),
+        email: "camila@example.co".to_string(),
+        identify: MonetixColombiaIdentifyModel {
+            doc_type: ColombiaDocType::Cc,
+            doc_number: "1020304050".to_string(),
+        },
+    }
+}
+
+pub fn pix_customer() -> MonetixPixCustomerModel {
+    MonetixPixCustomerModel {
+        id: "customer_id".to_string(),
+        ip_address: "127.0.0.1".to_string(),
+        first_name: "Joao".to_string(),
+        last_name: "Silva".to_string(),
+        email: "joao@example.com.br".to_string(),
+        identify: MonetixPixIdentifyModel {
+            doc_number: "52998224725".to_string(),
+        },
+    }
+}
+
+pub fn sign_string(request: &impl Serialize) -> String {
+    let json = serde_json::to_string(request).unwrap();
+
+    MonetixSigner::convert_to_sign_string(&json).unwrap()
+}
@@ -0,0 +1,102 @@
+use crate::rest::gate::models::{MonetixGateRequest, MonetixGeneralModel};
+use crate::rest::gate::validation::{Validate, ValidationErrors};
+use crate::rest::money::Money;
+use crate::rest::signer::MonetixRequest;
+use serde_derive::{Deserialize, Serialize};
+
+impl MonetixRequest for MonetixCaptureRequest {}
+
+impl MonetixGateRequest for MonetixCaptureRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Debits funds held by an auth operation.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixCaptureRequest {
+    pub general: MonetixGeneralModel,
+    pub payment: MonetixTwoStepPaymentModel,
+}
+
+impl MonetixRequest for MonetixCancelRequest {}
+
+impl MonetixGateRequest for MonetixCancelRequest {
+    fn general_mut(&mut self) -> &mut MonetixGeneralModel {
+        &mut self.general
+    }
+}
+
+/// Releases funds held by an auth operation.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixCancelRequest {
+    pub general: MonetixGeneralModel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment: Option<MonetixTwoStepPaymentModel>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MonetixTwoStepPaymentModel {
+    /// Must not exceed the held amount, the currency must match the auth currency
+    #[serde(flatten)]
+    pub money: Money,
+}
+
+impl Validate for MonetixCaptureRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+        errors.nested("payment", &self.payment);
+    }
+}
+
+impl Validate for MonetixCancelRequest {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.nested("general", &self.general);
+
+        if let Some(payment) = &self.payment {
+            errors.nested("payment", payment);
+        }
+    }
+}
+
+impl Validate for MonetixTwoStepPaymentModel {
+    fn collect_errors(&self, errors: &mut ValidationErrors) {
+        errors.check(self.money.minor() > 0, "amount", "must be positive");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::currencies::Currency;
+    use crate::rest::gate::test_fixtures::{general, sign_string};
+    use crate::rest::gate::two_step::{MonetixCancelRequest, MonetixCaptureRequest, MonetixTwoStepPaymentModel};
+    use crate::rest::money::Money;
+
+    #[test]
+    fn capture_sign_string() {
+        let request = MonetixCaptureRequest {
+            general: general(),
+            payment: MonetixTwoStepPaymentModel {
+                money: Money::from_minor(500, Currency::EUR),
+            },
+        };
+
+        assert_eq!(
+            sign_string(&request),
+            "general:merchant_callback_url:;general:payment_id:payment_id;general:project_id:1000;payment:amount:500;payment:currency:EUR"
+        );
+    }
+
+    #[test]
+    fn full_cancel_has_no_payment() {
+        let request = MonetixCancelRequest {
+            general: general(),
+            payment: None,
+        };
+
+        assert_eq!(
+            sign_string(&request),
+            "general:merchant_callback_url:;general:payment_id:payment_id;general:project_id:1000"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use std::net::IpAddr;
+
+/// Invalid field of a request, found before the request is sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Path of the field in the request, e.g. `customer.email`
+    pub field: String,
+    pub message: String,
+}
+
+/// All field errors of a request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            field: field.into(),
+            message: message.into(),
+        });
+    }
+
+    /// Adds an error if `is_valid` is false.
+    pub fn check(&mut self, is_valid: bool, field: &str, message: &str) {
+        if !is_valid {
+            self.add(field, message);
+        }
+    }
+
+    pub fn not_empty(&mut self, field: &str, value: &str) {
+        self.check(!value.trim().is_empty(), field, "must not be empty");
+    }
+
+    /// Validates a nested model, its field names get the `prefix.` prefix.
+    pub fn nested(&mut self, prefix: &str, value: &impl Validate) {
+        let mut nested = ValidationErrors::new();
+        value.collect_errors(&mut nested);
+
+        self.errors.extend(nested.errors.into_iter().map(|error| FieldError {
+            field: format!("{}.{}", prefix, error.field),
+            message: error.message,
+        }));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+
+            write!(f, "{}: {}", error.field, error.message)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// Local checks of the request data that Monetix would reject anyway.
+/// Currencies need no check, `Currency` can only hold known codes.
+pub trait Validate {
+    /// Adds an error for every invalid field.
+    fn collect_errors(&self, errors: &mut ValidationErrors);
+
+    /// Returns all field errors at once.
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        self.collect_errors(&mut errors);
+
+        errors.into_result()
+    }
+}
+
+pub fn is_valid_ip(value: &str) -> bool {
+    value.parse::<IpAddr>().is_ok()
+}
+
+/// Basic shape check: `local@domain.tld` without whitespace.
+pub fn is_valid_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && domain.split('.').all(|part| !part.is_empty())
+        && !value.chars().any(char::is_whitespace)
+}
+
+/// Card number of 12 to 19 digits with a valid Luhn check digit.
+pub fn is_valid_pan(value: &str) -> bool {
+    if !(12..=19).contains(&value.len()) || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+
+    let sum: u32 = value
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| {
+            let digit = (b - b'0') as u32;
+
+            match i % 2 {
+                0 => digit,
+                _ if digit > 4 => digit * 2 - 9,
+                _ => digit * 2,
+            }
+        })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::gate::validation::{is_valid_email, is_valid_ip, is_valid_pan, Validate, ValidationErrors};
+
+    struct Customer {
+        email: String,
+    }
+
+    impl Validate for Customer {
+        fn collect_errors(&self, errors: &mut ValidationErrors) {
+            errors.check(is_valid_email(&self.email), "email", "invalid email");
+        }
+    }
+
+    #[test]
+    fn emails() {
+        assert!(is_valid_email("user@example.com"));
+        assert!(is_valid_email("first.last+tag@mail.example.mx"));
+        assert!(!is_valid_email("user@example"));
+        assert!(!is_valid_email("@example.com"));
+        assert!(!is_valid_email("user@@example.com"));
+        assert!(!is_valid_email("user@example..com"));
+        assert!(!is_valid_email("us er@example.com"));
+    }
+
+    #[test]
+    fn ips() {
+        assert!(is_valid_ip("127.0.0.1"));
+        assert!(is_valid_ip("::1"));
+        assert!(!is_valid_ip("256.0.0.1"));
+        assert!(!is_valid_ip(""));
+    }
+
+    #[test]
+    fn pans() {
+        assert!(is_valid_pan("4000000000000077"));
+        assert!(is_valid_pan("4111111111111111"));
+        assert!(!is_valid_pan("4111111111111112"));
+        assert!(!is_valid_pan("41111111111"));
+        assert!(!is_valid_pan("4111 1111 1111 1111"));
+    }
+
+    #[test]
+    fn nested_errors() {
+        let mut errors = ValidationErrors::new();
+        errors.not_empty("id", " ");
+        errors.nested("customer", &Customer { email: "email".to_string() });
+
+        assert_eq!(errors.to_string(), "id: must not be empty; customer.email: invalid email");
+        assert!(errors.into_result().is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_PAYMENT_HOST_TTL: Duration = Duration::from_secs(300);
+
+/// Last payment host resolved via `/g2`, shared between clones of the client.
+#[derive(Debug)]
+pub(crate) struct PaymentHostCache {
+    ttl: Duration,
+    entry: Mutex<Option<CachedHost>>,
+    refreshing: AtomicBool,
+    /// Held while the first host is resolved, so that concurrent callers wait for one `/g2` request.
+    resolving: tokio::sync::Mutex<()>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedHost {
+    host: String,
+    resolved_at: Instant,
+}
+
+impl PaymentHostCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entry: Mutex::new(None),
+            refreshing: AtomicBool::new(false),
+            resolving: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Last known host and whether it is younger than the ttl.
+    pub fn get(&self) -> Option<(String, bool)> {
+        let entry = self.entry.lock().unwrap();
+
+        entry
+            .as_ref()
+            .map(|entry| (entry.host.clone(), entry.resolved_at.elapsed() < self.ttl))
+    }
+
+    pub fn set(&self, host: String) {
+        *self.entry.lock().unwrap() = Some(CachedHost {
+            host,
+            resolved_at: Instant::now(),
+        });
+    }
+
+    /// Returns `None` if another refresh is already running. The refresh lasts until the guard
+    /// is dropped, even if the refreshing task panics.
+    pub fn start_refresh(self: &Arc<Self>) -> Option<RefreshGuard> {
+        if self.refreshing.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+
+        Some(RefreshGuard { cache: self.clone() })
+    }
+
+    pub async fn lock_resolve(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.resolving.lock().await
+    }
+
+    #[cfg(test)]
+    pub fn is_refreshing(&self) -> bool {
+        self.refreshing.load(Ordering::SeqCst)
+    }
+}
+
+pub(crate) struct RefreshGuard {
+    cache: Arc<PaymentHostCache>,
+}
+
+impl Drop for RefreshGuard {
+    fn drop(&mut self) {
+        self.cache.refreshing.store(false, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::healthcheck::host_cache::PaymentHostCache;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn expiration() {
+        let cache = PaymentHostCache::new(Duration::from_secs(60));
+        assert!(cache.get().is_none());
+
+        cache.set("https://host".to_string());
+        assert_eq!(cache.get(), Some(("https://host".to_string(), true)));
+
+        let cache = PaymentHostCache::new(Duration::ZERO);
+        cache.set("https://host".to_string());
+        assert_eq!(cache.get(), Some(("https://host".to_string(), false)));
+    }
+
+    #[test]
+    fn single_refresh() {
+        let cache = Arc::new(PaymentHostCache::new(Duration::ZERO));
+
+        let guard = cache.start_refresh();
+        assert!(guard.is_some());
+        assert!(cache.start_refresh().is_none());
+
+        drop(guard);
+        assert!(!cache.is_refreshing());
+        assert!(cache.start_refresh().is_some());
+    }
+}
@@ -1,3 +1,4 @@
 pub mod rest_client;
 pub mod models;
-pub mod endpoints;
\ No newline at end of file
+pub mod endpoints;
+pub mod host_cache;
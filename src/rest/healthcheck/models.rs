@@ -1,11 +1,12 @@
 use serde_derive::{Deserialize, Serialize};
+use crate::rest::currencies::Currency;
 use crate::rest::signer::MonetixRequest;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GetPaymentPageArgs {
     pub payment_id: String,
     pub payment_amount: u64,
-    pub payment_currency: String,
+    pub payment_currency: Currency,
     pub project_id: u32,
     pub customer_id: String,
     pub customer_first_name: String,
@@ -20,7 +21,7 @@ pub struct PaymentPageConfig {
     pub encrypted_data: String,
     pub payment_id: String,
     pub payment_amount: u64,
-    pub payment_currency: String,
+    pub payment_currency: Currency,
     pub project_id: u32,
     pub customer_id: String,
     pub customer_first_name: String,
@@ -1,17 +1,25 @@
-use crate::rest::errors::Error;
+use crate::rest::errors::MonetixError;
 use reqwest::header::{HeaderMap, HeaderValue};
 use crate::rest::signer::MonetixSigner;
 use crate::rest::healthcheck::endpoints::MonetixHealthcheckEndpoint;
+use crate::rest::healthcheck::host_cache::{PaymentHostCache, DEFAULT_PAYMENT_HOST_TTL};
 use crate::rest::healthcheck::models::{GetPaymentPageArgs, PaymentPageConfig};
 use crate::rest::cipher::MonetixCipher;
+use crate::rest::transport::{MonetixHttpRequest, MonetixTransport, ReqwestTransport};
+use futures::future::join_all;
+use std::net::Ipv6Addr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[derive(Clone)]
 pub struct MonetixHealthcheckRestClient {
     cipher: MonetixCipher,
     signer: MonetixSigner,
-    host: String,
-    inner_client: reqwest::Client,
+    healthcheck_urls: Vec<String>,
+    transport: Arc<dyn MonetixTransport>,
     project_id: u32,
+    host_cache: Arc<PaymentHostCache>,
+    payment_host_scheme: String,
 }
 
 impl MonetixHealthcheckRestClient {
@@ -24,39 +32,155 @@ impl MonetixHealthcheckRestClient {
         Self {
             cipher: MonetixCipher::new(encryption_key),
             signer: MonetixSigner::new(secret_key),
-            host: api_url.into(),
-            inner_client: reqwest::Client::new(),
+            healthcheck_urls: vec![api_url.into()],
+            transport: Arc::new(ReqwestTransport::default()),
             project_id,
+            host_cache: Arc::new(PaymentHostCache::new(DEFAULT_PAYMENT_HOST_TTL)),
+            payment_host_scheme: "https".to_string(),
         }
     }
 
-    pub async fn get_payment_host(&self) -> Result<String, Error> {
+    /// Replaces the default reqwest transport, e.g. with `MockTransport` in tests.
+    pub fn with_transport(mut self, transport: Arc<dyn MonetixTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// How long a payment host resolved via `/g2` is used before it is refreshed. Default is 5 minutes.
+    pub fn with_host_ttl(mut self, ttl: Duration) -> Self {
+        self.host_cache = Arc::new(PaymentHostCache::new(ttl));
+        self
+    }
+
+    /// Scheme added to the host returned by `/g2`. Default is `https`, use `http` for a local simulator.
+    pub fn with_payment_host_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.payment_host_scheme = scheme.into();
+        self
+    }
+
+    /// Healthcheck urls tried in order until one answers with a valid host.
+    /// Replaces the url passed to `new`. Fails if `urls` is empty.
+    pub fn with_healthcheck_urls(
+        mut self,
+        urls: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, MonetixError> {
+        let urls: Vec<String> = urls.into_iter().map(Into::into).collect();
+
+        if urls.is_empty() {
+            return Err(MonetixError::NoHealthcheckUrls);
+        }
+
+        self.healthcheck_urls = urls;
+        Ok(self)
+    }
+
+    /// Requests the payment host from `/g2` of every healthcheck url in order, bypassing the cache.
+    /// Returns the error of the last url if none of them answered with a valid host.
+    pub async fn get_payment_host(&self) -> Result<String, MonetixError> {
+        let mut last_error = None;
+
+        for url in &self.healthcheck_urls {
+            match self.request_payment_host(url).await {
+                Ok(host) => return Ok(host),
+                Err(err) => last_error = Some(err),
+            }
+        }
+
+        Err(last_error.unwrap_or(MonetixError::NoHealthcheckUrls))
+    }
+
+    /// Requests `/g2` of every healthcheck url concurrently, for readiness checks.
+    /// Probes are returned in the order of the urls.
+    pub async fn probe(&self) -> Vec<MonetixHealthcheckProbe> {
+        let probes = self.healthcheck_urls.iter().map(|url| async move {
+            let started = Instant::now();
+            let result = self.request_payment_host(url).await;
+
+            MonetixHealthcheckProbe {
+                healthcheck_url: url.clone(),
+                latency: started.elapsed(),
+                result,
+            }
+        });
+
+        join_all(probes).await
+    }
+
+    async fn request_payment_host(&self, healthcheck_url: &str) -> Result<String, MonetixError> {
         let endpoint = MonetixHealthcheckEndpoint::PaymentHost;
-        let resp = self.get_string(&self.host, endpoint, None).await?;
+        let url = format!("{}{}", healthcheck_url, String::from(&endpoint));
+        let body = self.get_string(healthcheck_url, endpoint, None).await?;
+
+        match parse_payment_host(&body) {
+            Some(host) => Ok(format!("{}://{}", self.payment_host_scheme, host)),
+            None => Err(MonetixError::InvalidPaymentHost { url, body }),
+        }
+    }
 
-        Ok(format!("https://{}", resp.trim()))
+    /// Payment host from the cache. An expired host is returned right away and refreshed
+    /// in the background; if the refresh fails, the last known host stays in use.
+    /// Only the very first call waits for `/g2`.
+    pub async fn cached_payment_host(&self) -> Result<String, MonetixError> {
+        match self.host_cache.get() {
+            Some((host, true)) => Ok(host),
+            Some((host, false)) => {
+                self.spawn_host_refresh();
+                Ok(host)
+            }
+            None => {
+                let _resolving = self.host_cache.lock_resolve().await;
+
+                // another caller may have resolved the host while this one was waiting
+                match self.host_cache.get() {
+                    Some((host, _)) => Ok(host),
+                    None => self.refresh_payment_host().await,
+                }
+            }
+        }
     }
 
-    pub async fn get_payment_url(&self, args: GetPaymentPageArgs) -> Result<String, Error> {
+    /// Requests the payment host from `/g2` and stores it in the cache.
+    /// On error the cache keeps the last known host.
+    pub async fn refresh_payment_host(&self) -> Result<String, MonetixError> {
         let host = self.get_payment_host().await?;
+        self.host_cache.set(host.clone());
+
+        Ok(host)
+    }
+
+    fn spawn_host_refresh(&self) {
+        let Some(guard) = self.host_cache.start_refresh() else {
+            return;
+        };
+
+        let client = self.clone();
+
+        tokio::spawn(async move {
+            let _guard = guard;
+            let _ = client.refresh_payment_host().await;
+        });
+    }
+
+    pub async fn get_payment_url(&self, args: GetPaymentPageArgs) -> Result<String, MonetixError> {
+        let host = self.cached_payment_host().await?;
         let query = serde_qs::to_string(&args).unwrap();
         let endpoint = MonetixHealthcheckEndpoint::PaymentUrl;
-        let sign = self.signer.generate_sign(&args)?;
+        let sign = self.signer.generate_sign(&args).map_err(MonetixError::Signing)?;
         let args = format!("{}?{}&signature={}", String::from(&endpoint), query, sign);
-        let encrypted = self.cipher.encrypt(&args)?;
+        let encrypted = self.cipher.encrypt(&args).map_err(MonetixError::Encryption)?;
         let url = format!("{}/{}/{}", host, self.project_id, encrypted);
 
         Ok(url)
     }
 
-    pub async fn get_payment_page_config(&self, args: GetPaymentPageArgs) -> Result<PaymentPageConfig, Error> {
-        let host = self.get_payment_host().await?;
+    pub async fn get_payment_page_config(&self, args: GetPaymentPageArgs) -> Result<PaymentPageConfig, MonetixError> {
+        let host = self.cached_payment_host().await?;
         let query = serde_qs::to_string(&args).unwrap();
         let endpoint = MonetixHealthcheckEndpoint::PaymentUrl;
-        let signature = self.signer.generate_sign(&args)?;
+        let signature = self.signer.generate_sign(&args).map_err(MonetixError::Signing)?;
         let signature_url_encoded = urlencoding::encode(&signature).to_string();
         let args_string = format!("{}?{}&signature={}", String::from(&endpoint), query, signature_url_encoded);
-        let encrypted_data = self.cipher.encrypt(&args_string)?;
+        let encrypted_data = self.cipher.encrypt(&args_string).map_err(MonetixError::Encryption)?;
         
         Ok(PaymentPageConfig {
             host,
@@ -73,8 +197,8 @@ impl MonetixHealthcheckRestClient {
         })
     }
 
-    pub async fn get_payment_sign(&self, args: GetPaymentPageArgs) -> Result<String, Error> {
-        let sign = self.signer.generate_sign(&args)?;
+    pub async fn get_payment_sign(&self, args: GetPaymentPageArgs) -> Result<String, MonetixError> {
+        let sign = self.signer.generate_sign(&args).map_err(MonetixError::Signing)?;
 
         Ok(sign)
     }
@@ -84,20 +208,35 @@ impl MonetixHealthcheckRestClient {
         host: &str,
         endpoint: MonetixHealthcheckEndpoint,
         query: Option<&str>,
-    ) -> Result<String, Error> {
+    ) -> Result<String, MonetixError> {
         let url = if let Some(query) = query  {
             let args = format!("{}?{}", String::from(&endpoint), query);
-            let sign = self.cipher.encrypt(&args)?;
+            let sign = self.cipher.encrypt(&args).map_err(MonetixError::Encryption)?;
             
             format!("{}/{}/{}", host, self.project_id, sign)
         } else {
             format!("{}{}", host, String::from(&endpoint))
         };
 
-        let headers = self.build_headers();
-        let response = self.inner_client.get(&url).headers(headers).send().await?;
+        let request = MonetixHttpRequest {
+            method: endpoint.get_http_method(),
+            url,
+            headers: self.build_headers(),
+            body: None,
+            timeout: None,
+        };
+        let url = request.url.clone();
+        let response = self.transport.send(request).await?;
+
+        if !response.status.is_success() {
+            return Err(MonetixError::Http {
+                status: response.status,
+                url,
+                body: response.body,
+            });
+        }
 
-        Ok(response.text().await?)
+        Ok(response.body)
     }
 
     fn build_headers(&self) -> HeaderMap {
@@ -111,3 +250,266 @@ impl MonetixHealthcheckRestClient {
         custom_headers
     }
 }
+
+/// Result of requesting `/g2` of a single healthcheck url.
+#[derive(Debug)]
+pub struct MonetixHealthcheckProbe {
+    pub healthcheck_url: String,
+    pub latency: Duration,
+    /// Payment host with the scheme, `https://` by default
+    pub result: Result<String, MonetixError>,
+}
+
+impl MonetixHealthcheckProbe {
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Host name or bracketed IPv6 address with an optional port, e.g. `paymentpage.example.com`,
+/// `127.0.0.1:8080` or `[::1]:8080`.
+fn parse_payment_host(body: &str) -> Option<&str> {
+    let host = body.trim();
+
+    if let Some(address) = host.strip_prefix('[') {
+        let (address, port) = address.split_once(']')?;
+        address.parse::<Ipv6Addr>().ok()?;
+
+        if !port.is_empty() {
+            port.strip_prefix(':')?.parse::<u16>().ok()?;
+        }
+
+        return Some(host);
+    }
+
+    let (name, port) = match host.rsplit_once(':') {
+        Some((name, port)) => (name, Some(port)),
+        None => (host, None),
+    };
+
+    if let Some(port) = port {
+        port.parse::<u16>().ok()?;
+    }
+
+    let is_label_valid = |label: &str| {
+        (1..=63).contains(&label.len())
+            && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+    };
+    let is_valid = name.len() <= 253 && name.contains('.') && name.split('.').all(is_label_valid);
+
+    is_valid.then_some(host)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::errors::MonetixError;
+    use crate::rest::healthcheck::rest_client::{parse_payment_host, MonetixHealthcheckRestClient};
+    use crate::rest::transport::{MockTransport, MonetixHttpRequest, MonetixHttpResponse, MonetixTransport};
+    use async_trait::async_trait;
+    use reqwest::StatusCode;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn get_payment_host() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::OK, "paymentpage.example.com\n");
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_transport(transport.clone());
+
+        let host = client.get_payment_host().await.unwrap();
+
+        assert_eq!(host, "https://paymentpage.example.com");
+        assert_eq!(transport.requests()[0].url, "https://health/g2");
+    }
+
+    async fn wait_for_refresh(client: &MonetixHealthcheckRestClient) {
+        for _ in 0..1000 {
+            if !client.host_cache.is_refreshing() {
+                return;
+            }
+
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+
+        panic!("payment host refresh did not finish");
+    }
+
+    #[tokio::test]
+    async fn payment_host_is_cached() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::OK, "paymentpage.example.com");
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_transport(transport.clone());
+
+        let first = client.cached_payment_host().await.unwrap();
+        let second = client.clone().cached_payment_host().await.unwrap();
+
+        assert_eq!(first, "https://paymentpage.example.com");
+        assert_eq!(second, first);
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn expired_host_is_refreshed_in_background() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::OK, "old.example.com");
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_transport(transport.clone())
+            .with_host_ttl(Duration::ZERO);
+
+        client.cached_payment_host().await.unwrap();
+
+        // failed refresh keeps the last known host
+        transport.push_response(StatusCode::SERVICE_UNAVAILABLE, "");
+        assert_eq!(client.cached_payment_host().await.unwrap(), "https://old.example.com");
+        wait_for_refresh(&client).await;
+        assert_eq!(client.host_cache.get().unwrap().0, "https://old.example.com");
+
+        transport.push_response(StatusCode::OK, "new.example.com");
+        client.cached_payment_host().await.unwrap();
+        wait_for_refresh(&client).await;
+
+        assert_eq!(client.host_cache.get().unwrap().0, "https://new.example.com");
+    }
+
+    #[tokio::test]
+    async fn panicked_refresh_is_finished() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::OK, "old.example.com");
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_transport(transport.clone())
+            .with_host_ttl(Duration::ZERO);
+
+        client.cached_payment_host().await.unwrap();
+
+        // no response is queued, so the mock panics inside the refresh task
+        client.cached_payment_host().await.unwrap();
+        wait_for_refresh(&client).await;
+
+        transport.push_response(StatusCode::OK, "new.example.com");
+        client.cached_payment_host().await.unwrap();
+        wait_for_refresh(&client).await;
+
+        assert_eq!(client.host_cache.get().unwrap().0, "https://new.example.com");
+    }
+
+    /// Answers after a delay, so that concurrent callers overlap.
+    struct SlowTransport(Arc<MockTransport>);
+
+    #[async_trait]
+    impl MonetixTransport for SlowTransport {
+        async fn send(&self, request: MonetixHttpRequest) -> Result<MonetixHttpResponse, MonetixError> {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.0.send(request).await
+        }
+    }
+
+    #[tokio::test]
+    async fn first_resolve_is_shared() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::OK, "paymentpage.example.com");
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_transport(Arc::new(SlowTransport(transport.clone())));
+
+        let (first, second) = tokio::join!(client.cached_payment_host(), client.cached_payment_host());
+
+        assert_eq!(first.unwrap(), "https://paymentpage.example.com");
+        assert_eq!(second.unwrap(), "https://paymentpage.example.com");
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn first_resolve_fails_without_known_host() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::BAD_GATEWAY, "");
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_transport(transport.clone());
+
+        assert!(matches!(
+            client.cached_payment_host().await,
+            Err(MonetixError::Http { .. })
+        ));
+    }
+
+    #[test]
+    fn payment_host_format() {
+        assert_eq!(parse_payment_host("paymentpage.example.com\n"), Some("paymentpage.example.com"));
+        assert_eq!(parse_payment_host("127.0.0.1:8080"), Some("127.0.0.1:8080"));
+        assert_eq!(parse_payment_host(""), None);
+        assert_eq!(parse_payment_host("localhost"), None);
+        assert_eq!(parse_payment_host("<html>error</html>"), None);
+        assert_eq!(parse_payment_host("https://paymentpage.example.com"), None);
+        assert_eq!(parse_payment_host("-bad.example.com"), None);
+        assert_eq!(parse_payment_host("example.com:http"), None);
+        assert_eq!(parse_payment_host("[::1]"), Some("[::1]"));
+        assert_eq!(parse_payment_host("[2001:db8::1]:8443\n"), Some("[2001:db8::1]:8443"));
+        assert_eq!(parse_payment_host("[2001:db8::1]8443"), None);
+        assert_eq!(parse_payment_host("[not:an:address]:8443"), None);
+        assert_eq!(parse_payment_host("2001:db8::1"), None);
+    }
+
+    #[tokio::test]
+    async fn failover_to_next_healthcheck_url() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::SERVICE_UNAVAILABLE, "");
+        transport.push_response(StatusCode::OK, "Service is down");
+        transport.push_response(StatusCode::OK, "paymentpage.example.com");
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_transport(transport.clone())
+            .with_healthcheck_urls(["https://health1", "https://health2", "https://health3"])
+            .unwrap();
+
+        let host = client.get_payment_host().await.unwrap();
+        let urls: Vec<_> = transport.requests().into_iter().map(|request| request.url).collect();
+
+        assert_eq!(host, "https://paymentpage.example.com");
+        assert_eq!(urls, vec!["https://health1/g2", "https://health2/g2", "https://health3/g2"]);
+    }
+
+    #[tokio::test]
+    async fn all_healthcheck_urls_fail() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::SERVICE_UNAVAILABLE, "");
+        transport.push_response(StatusCode::OK, "  ");
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_transport(transport.clone())
+            .with_healthcheck_urls(["https://health1", "https://health2"])
+            .unwrap();
+
+        let result = client.get_payment_host().await;
+
+        assert!(matches!(
+            result,
+            Err(MonetixError::InvalidPaymentHost { url, .. }) if url == "https://health2/g2"
+        ));
+    }
+
+    #[test]
+    fn empty_healthcheck_urls() {
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_healthcheck_urls(Vec::<String>::new());
+
+        assert!(matches!(client, Err(MonetixError::NoHealthcheckUrls)));
+    }
+
+    #[tokio::test]
+    async fn probe_every_url() {
+        let transport = Arc::new(MockTransport::new());
+        transport.push_response(StatusCode::OK, "paymentpage.example.com");
+        transport.push_response(StatusCode::BAD_GATEWAY, "");
+        let client = MonetixHealthcheckRestClient::new(1000, "secret", "key", "https://health")
+            .with_transport(transport.clone())
+            .with_healthcheck_urls(["https://health1", "https://health2"])
+            .unwrap();
+
+        let probes = client.probe().await;
+
+        assert_eq!(probes.len(), 2);
+        assert_eq!(probes[0].healthcheck_url, "https://health1");
+        assert_eq!(probes[0].result.as_deref().unwrap(), "https://paymentpage.example.com");
+        assert!(!probes[1].is_ok());
+    }
+}
@@ -0,0 +1,339 @@
+use crate::rest::callback::MonetixCallbackModel;
+use crate::rest::status::PaymentStatus;
+use std::collections::HashSet;
+
+/// Simplified payment lifecycle used to validate the order of incoming callbacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaymentState {
+    Created,
+    Processing,
+    AwaitingThreeDs,
+    AwaitingRedirect,
+    Success,
+    Decline,
+    PartiallyRefunded,
+    Refunded,
+    PartiallyReversed,
+    Reversed,
+}
+
+impl PaymentState {
+    /// Maps a callback payment status into a lifecycle state.
+    /// Returns `None` for statuses this connector doesn't know about.
+    pub fn from_status(status: &PaymentStatus) -> Option<Self> {
+        let state = match status {
+            PaymentStatus::Processing
+            | PaymentStatus::AwaitingCustomer
+            | PaymentStatus::AwaitingClarification
+            | PaymentStatus::AwaitingCapture
+            | PaymentStatus::AwaitingConfirmation
+            | PaymentStatus::ExternalProcessing
+            | PaymentStatus::InvoiceSent => PaymentState::Processing,
+            PaymentStatus::AwaitingThreeDsResult => PaymentState::AwaitingThreeDs,
+            PaymentStatus::AwaitingRedirectResult => PaymentState::AwaitingRedirect,
+            PaymentStatus::Success => PaymentState::Success,
+            PaymentStatus::Decline
+            | PaymentStatus::Cancelled
+            | PaymentStatus::Expired
+            | PaymentStatus::Error
+            | PaymentStatus::InternalError
+            | PaymentStatus::ExternalError => PaymentState::Decline,
+            PaymentStatus::PartiallyRefunded => PaymentState::PartiallyRefunded,
+            PaymentStatus::Refunded => PaymentState::Refunded,
+            PaymentStatus::PartiallyReversed => PaymentState::PartiallyReversed,
+            PaymentStatus::Reversed => PaymentState::Reversed,
+            PaymentStatus::Unknown(_) => return None,
+        };
+
+        Some(state)
+    }
+
+    /// States every partial refund or reversal moves into again, each with its own callback.
+    pub fn is_repeatable(&self) -> bool {
+        matches!(self, PaymentState::PartiallyRefunded | PaymentState::PartiallyReversed)
+    }
+
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            PaymentState::Decline | PaymentState::Refunded | PaymentState::Reversed
+        )
+    }
+
+    pub fn can_transition_to(&self, next: PaymentState) -> bool {
+        use PaymentState::*;
+
+        match self {
+            Created => matches!(
+                next,
+                Processing | AwaitingThreeDs | AwaitingRedirect | Success | Decline
+            ),
+            Processing | AwaitingThreeDs | AwaitingRedirect => matches!(
+                next,
+                Processing | AwaitingThreeDs | AwaitingRedirect | Success | Decline
+            ),
+            Success => matches!(
+                next,
+                PartiallyRefunded | Refunded | PartiallyReversed | Reversed
+            ),
+            PartiallyRefunded => matches!(next, PartiallyRefunded | Refunded),
+            PartiallyReversed => matches!(next, PartiallyReversed | Reversed),
+            Decline | Refunded | Reversed => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transition {
+    /// The state was changed.
+    Applied { from: PaymentState, to: PaymentState },
+    /// The callback repeats the current state, nothing was changed.
+    Duplicate(PaymentState),
+    /// The callback would move the payment backwards or out of a final state.
+    Rejected { from: PaymentState, to: PaymentState },
+    /// The callback has a status unknown to the connector.
+    UnknownStatus(PaymentStatus),
+    /// The callback belongs to another payment.
+    PaymentMismatch { expected: String, actual: String },
+}
+
+impl Transition {
+    pub fn is_applied(&self) -> bool {
+        matches!(self, Transition::Applied { .. })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentStateMachine {
+    payment_id: String,
+    state: PaymentState,
+    /// Operations that moved the payment into a repeatable state, see `PaymentState::is_repeatable`.
+    applied_operations: HashSet<String>,
+}
+
+impl PaymentStateMachine {
+    pub fn new(payment_id: impl Into<String>) -> Self {
+        Self::with_state(payment_id, PaymentState::Created)
+    }
+
+    /// Restores the machine from a previously persisted state.
+    pub fn with_state(payment_id: impl Into<String>, state: PaymentState) -> Self {
+        Self {
+            payment_id: payment_id.into(),
+            state,
+            applied_operations: HashSet::new(),
+        }
+    }
+
+    /// Restores the operations returned by `applied_operations`, so retried partial refund
+    /// or reversal callbacks are still recognized after a restart.
+    pub fn with_applied_operations<T: Into<String>>(mut self, operations: impl IntoIterator<Item = T>) -> Self {
+        self.applied_operations.extend(operations.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn applied_operations(&self) -> impl Iterator<Item = &str> {
+        self.applied_operations.iter().map(String::as_str)
+    }
+
+    pub fn payment_id(&self) -> &str {
+        &self.payment_id
+    }
+
+    pub fn state(&self) -> PaymentState {
+        self.state
+    }
+
+    /// Validates the callback against the current state and moves to the new state if allowed.
+    /// The state is left untouched for any result except `Transition::Applied`.
+    pub fn apply(&mut self, callback: &MonetixCallbackModel) -> Transition {
+        if callback.payment.id != self.payment_id {
+            return Transition::PaymentMismatch {
+                expected: self.payment_id.clone(),
+                actual: callback.payment.id.clone(),
+            };
+        }
+
+        let operation = callback.operation.as_ref().map(|operation| match operation.id {
+            Some(id) => id.to_string(),
+            None => operation.request_id.clone(),
+        });
+
+        self.apply_operation(&callback.payment.status, operation)
+    }
+
+    /// Same as `apply` without the operation. A repeated partial refund or reversal can't be told
+    /// apart from a retried callback here, so it is always a `Transition::Duplicate`.
+    pub fn apply_status(&mut self, status: &PaymentStatus) -> Transition {
+        self.apply_operation(status, None)
+    }
+
+    fn apply_operation(&mut self, status: &PaymentStatus, operation: Option<String>) -> Transition {
+        let Some(next) = PaymentState::from_status(status) else {
+            return Transition::UnknownStatus(status.clone());
+        };
+
+        let from = self.state;
+
+        if next.is_repeatable() {
+            let is_new = operation
+                .as_ref()
+                .is_some_and(|operation| !self.applied_operations.contains(operation));
+
+            if from == next && !is_new {
+                return Transition::Duplicate(from);
+            }
+        } else if from == next {
+            return Transition::Duplicate(from);
+        }
+
+        if !from.can_transition_to(next) {
+            return Transition::Rejected { from, to: next };
+        }
+
+        if next.is_repeatable() {
+            if let Some(operation) = operation {
+                self.applied_operations.insert(operation);
+            }
+        }
+
+        self.state = next;
+
+        Transition::Applied { from, to: next }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::lifecycle::{PaymentState, PaymentStateMachine, Transition};
+    use crate::rest::callback::MonetixCallbackModel;
+    use crate::rest::status::PaymentStatus;
+
+    fn callback(payment_id: &str, status: &str) -> MonetixCallbackModel {
+        let json = format!(
+            r#"{{"customer": {{"id": "c"}}, "payment": {{"id": "{payment_id}", "status": "{status}", "type": "purchase", "sum": {{}}}}, "project_id": 1, "signature": ""}}"#
+        );
+
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn refund_callback(status: &str, operation_id: u64) -> MonetixCallbackModel {
+        let mut callback = callback("payment_id", status);
+        let operation = format!(
+            r#"{{"id": {operation_id}, "request_id": "request_{operation_id}", "status": "success", "type": "refund"}}"#
+        );
+        callback.operation = Some(serde_json::from_str(&operation).unwrap());
+
+        callback
+    }
+
+    #[test]
+    fn apply_callback() {
+        let mut machine = PaymentStateMachine::new("payment_id");
+
+        assert!(machine.apply(&callback("payment_id", "success")).is_applied());
+        assert_eq!(machine.state(), PaymentState::Success);
+        assert!(matches!(
+            machine.apply(&callback("other_id", "refunded")),
+            Transition::PaymentMismatch { .. }
+        ));
+        assert_eq!(machine.state(), PaymentState::Success);
+    }
+
+    #[test]
+    fn happy_path() {
+        let mut machine = PaymentStateMachine::new("payment_id");
+
+        assert!(machine.apply_status(&PaymentStatus::Processing).is_applied());
+        assert!(machine.apply_status(&PaymentStatus::AwaitingThreeDsResult).is_applied());
+        assert!(machine.apply_status(&PaymentStatus::Success).is_applied());
+        assert!(machine.apply_status(&PaymentStatus::Refunded).is_applied());
+        assert_eq!(machine.state(), PaymentState::Refunded);
+    }
+
+    #[test]
+    fn success_to_processing_rejected() {
+        let mut machine = PaymentStateMachine::with_state("payment_id", PaymentState::Success);
+        let transition = machine.apply_status(&PaymentStatus::Processing);
+
+        assert_eq!(
+            transition,
+            Transition::Rejected {
+                from: PaymentState::Success,
+                to: PaymentState::Processing
+            }
+        );
+        assert_eq!(machine.state(), PaymentState::Success);
+    }
+
+    #[test]
+    fn duplicate_callback() {
+        let mut machine = PaymentStateMachine::with_state("payment_id", PaymentState::Success);
+        let transition = machine.apply_status(&PaymentStatus::Success);
+
+        assert_eq!(transition, Transition::Duplicate(PaymentState::Success));
+
+        let mut machine = PaymentStateMachine::with_state("payment_id", PaymentState::Processing);
+        let transition = machine.apply_status(&PaymentStatus::Processing);
+
+        assert_eq!(transition, Transition::Duplicate(PaymentState::Processing));
+    }
+
+    #[test]
+    fn repeated_partial_refund_applied() {
+        let mut machine = PaymentStateMachine::with_state("payment_id", PaymentState::Success);
+
+        assert!(machine.apply(&refund_callback("partially refunded", 1)).is_applied());
+        assert!(machine.apply(&refund_callback("partially refunded", 2)).is_applied());
+        assert!(machine.apply(&refund_callback("refunded", 3)).is_applied());
+        assert_eq!(machine.state(), PaymentState::Refunded);
+    }
+
+    #[test]
+    fn retried_partial_refund_is_duplicate() {
+        let mut machine = PaymentStateMachine::with_state("payment_id", PaymentState::Success);
+
+        assert!(machine.apply(&refund_callback("partially refunded", 1)).is_applied());
+        assert_eq!(
+            machine.apply(&refund_callback("partially refunded", 1)),
+            Transition::Duplicate(PaymentState::PartiallyRefunded)
+        );
+        assert_eq!(
+            machine.apply_status(&PaymentStatus::PartiallyRefunded),
+            Transition::Duplicate(PaymentState::PartiallyRefunded)
+        );
+
+        let operations: Vec<_> = machine.applied_operations().map(str::to_string).collect();
+        let mut restored = PaymentStateMachine::with_state("payment_id", PaymentState::PartiallyRefunded)
+            .with_applied_operations(operations);
+
+        assert_eq!(
+            restored.apply(&refund_callback("partially refunded", 1)),
+            Transition::Duplicate(PaymentState::PartiallyRefunded)
+        );
+    }
+
+    #[test]
+    fn created_to_refunded_rejected() {
+        let mut machine = PaymentStateMachine::new("payment_id");
+
+        assert_eq!(
+            machine.apply(&refund_callback("refunded", 1)),
+            Transition::Rejected {
+                from: PaymentState::Created,
+                to: PaymentState::Refunded
+            }
+        );
+        assert!(!machine.apply_status(&PaymentStatus::Reversed).is_applied());
+        assert!(machine.apply_status(&PaymentStatus::Success).is_applied());
+    }
+
+    #[test]
+    fn unknown_status() {
+        let mut machine = PaymentStateMachine::new("payment_id");
+        let status = PaymentStatus::Unknown("new status".to_string());
+
+        assert_eq!(machine.apply_status(&status), Transition::UnknownStatus(status));
+        assert_eq!(machine.state(), PaymentState::Created);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod errors;
 pub mod currencies;
+pub mod money;
 pub mod callback;
 pub mod gate;
 pub mod healthcheck;
@@ -7,3 +8,12 @@ pub mod response_handler;
 pub mod cipher;
 pub mod signer;
 pub mod payment_page;
+pub mod transport;
+pub mod status;
+pub mod lifecycle;
+#[cfg(test)]
+mod test_fixtures;
+#[cfg(feature = "axum")]
+pub mod webhook;
+#[cfg(feature = "simulator")]
+pub mod simulator;
@@ -0,0 +1,176 @@
+use crate::rest::currencies::Currency;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Drops extra digits: 10.109 USD -> 10.10
+    Truncate,
+    /// Rounds half to the nearest even digit: 10.105 USD -> 10.10, 10.115 USD -> 10.12
+    HalfEven,
+    /// Rounds half away from zero: 10.105 USD -> 10.11
+    HalfUp,
+}
+
+impl From<Rounding> for RoundingStrategy {
+    fn from(rounding: Rounding) -> Self {
+        match rounding {
+            Rounding::Truncate => RoundingStrategy::ToZero,
+            Rounding::HalfEven => RoundingStrategy::MidpointNearestEven,
+            Rounding::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MoneyError {
+    #[error("Invalid amount {0}")]
+    InvalidAmount(String),
+    #[error("Amount {0} doesn't fit into minor units")]
+    Overflow(String),
+}
+
+/// Amount in minor currency units, e.g. cents. Serialized as Monetix `amount` and `currency` fields.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Money {
+    minor: i64,
+    currency: Currency,
+}
+
+impl Money {
+    pub fn from_minor(minor: i64, currency: Currency) -> Self {
+        Self { minor, currency }
+    }
+
+    pub fn from_decimal(amount: Decimal, currency: Currency, rounding: Rounding) -> Result<Self, MoneyError> {
+        let rounded = amount.round_dp_with_strategy(currency.minor_digits(), rounding.into());
+        let minor = rounded
+            .checked_mul(Decimal::from(10_i64.pow(currency.minor_digits())))
+            .and_then(|minor| minor.to_i64())
+            .ok_or_else(|| MoneyError::Overflow(amount.to_string()))?;
+
+        Ok(Self { minor, currency })
+    }
+
+    /// Parses an amount in major units like `10.25` or `1e-7` without going through floats.
+    pub fn from_decimal_str(amount: &str, currency: Currency, rounding: Rounding) -> Result<Self, MoneyError> {
+        let amount = amount.trim();
+        let decimal = if amount.contains(['e', 'E']) {
+            Decimal::from_scientific(amount)
+        } else {
+            Decimal::from_str_exact(amount)
+        };
+        let decimal = decimal.map_err(|_| MoneyError::InvalidAmount(amount.to_string()))?;
+
+        Money::from_decimal(decimal, currency, rounding)
+    }
+
+    pub fn minor(&self) -> i64 {
+        self.minor
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Amount in major units, e.g. 10.25 for 1025 cents.
+    pub fn to_decimal(&self) -> Decimal {
+        Decimal::new(self.minor, self.currency.minor_digits())
+    }
+}
+
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.to_decimal(), self.currency)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct MoneyModel {
+    amount: i64,
+    currency: Currency,
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MoneyModel {
+            amount: self.minor,
+            currency: self.currency,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let model = MoneyModel::deserialize(deserializer)?;
+
+        Ok(Money::from_minor(model.amount, model.currency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::currencies::Currency;
+    use crate::rest::money::{Money, MoneyError, Rounding};
+
+    #[test]
+    fn from_decimal_str() {
+        let money = Money::from_decimal_str("10.1", Currency::USD, Rounding::Truncate).unwrap();
+
+        assert_eq!(money.minor(), 1010);
+        assert_eq!(money.to_string(), "10.10 USD");
+    }
+
+    #[test]
+    fn rounding() {
+        let amount = |value: &str, rounding| Money::from_decimal_str(value, Currency::USD, rounding).unwrap().minor();
+
+        assert_eq!(amount("10.109", Rounding::Truncate), 1010);
+        assert_eq!(amount("10.105", Rounding::HalfEven), 1010);
+        assert_eq!(amount("10.115", Rounding::HalfEven), 1012);
+        assert_eq!(amount("10.105", Rounding::HalfUp), 1011);
+        assert_eq!(amount("-10.105", Rounding::HalfUp), -1011);
+    }
+
+    #[test]
+    fn scientific_notation() {
+        let money = Money::from_decimal_str("1e-7", Currency::USD, Rounding::HalfUp).unwrap();
+        assert_eq!(money.minor(), 0);
+
+        let money = Money::from_decimal_str("1.5E2", Currency::USD, Rounding::HalfUp).unwrap();
+        assert_eq!(money.minor(), 15000);
+    }
+
+    #[test]
+    fn zero_minor_digits() {
+        let money = Money::from_decimal_str("1500.5", Currency::JPY, Rounding::HalfUp).unwrap();
+
+        assert_eq!(money.minor(), 1501);
+    }
+
+    #[test]
+    fn invalid_amount() {
+        let result = Money::from_decimal_str("10,5", Currency::USD, Rounding::HalfUp);
+
+        assert_eq!(result, Err(MoneyError::InvalidAmount("10,5".to_string())));
+    }
+
+    #[test]
+    fn overflow() {
+        let result = Money::from_decimal_str("100000000000000000000", Currency::USD, Rounding::HalfUp);
+
+        assert!(matches!(result, Err(MoneyError::Overflow(_))));
+    }
+
+    #[test]
+    fn serde() {
+        let money = Money::from_minor(1000, Currency::USD);
+        let json = serde_json::to_string(&money).unwrap();
+
+        assert_eq!(json, r#"{"amount":1000,"currency":"USD"}"#);
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), money);
+        assert!(serde_json::from_str::<Money>(r#"{"amount":1,"currency":"XXX1"}"#).is_err());
+    }
+}
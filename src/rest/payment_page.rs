@@ -1,36 +1,172 @@
 use crate::rest::healthcheck::models::PaymentPageConfig;
+use serde::Serialize;
 
 pub struct PaymentPage {
-    html_template: &'static str,
     config: PaymentPageConfig,
 }
 
+/// Widget config passed to `EPayWidget.create`.
+#[derive(Serialize)]
+struct WidgetConfig<'a> {
+    payment_id: &'a str,
+    payment_amount: u64,
+    payment_currency: &'a str,
+    project_id: u32,
+    customer_id: &'a str,
+    customer_first_name: &'a str,
+    customer_last_name: &'a str,
+    customer_email: &'a str,
+    target_element: &'a str,
+    signature: &'a str,
+}
+
 impl PaymentPage {
     pub fn new(config: PaymentPageConfig) -> Self {
-        Self {
-            html_template: "<html><head><link rel='stylesheet' href='TEMPLATE_PAYMENT_HOST/shared/merchant.css'><script src='TEMPLATE_PAYMENT_HOST/shared/merchant.js'></script></head><body><div class='container'><div class='cart-info'></div><div id='widget-container'></div></div><script type='text/javascript'>var configObj={payment_id:'TEMPLATE_PAYMENT_ID',payment_amount:TEMPLATE_PAYMENT_AMOUNT,payment_currency:'TEMPLATE_PAYMENT_CURRENCY',project_id:TEMPLATE_PROJECT_ID,customer_id:'TEMPLATE_CUSTOMER_ID',customer_first_name:'TEMPLATE_CUSTOMER_FIRST_NAME',customer_last_name:'TEMPLATE_CUSTOMER_LAST_NAME',customer_email:'TEMPLATE_CUSTOMER_EMAIL',target_element:'widget-container',signature:'TEMPLATE_SIGNATURE'};var widget=EPayWidget.create(configObj);var url=widget.buildUrl();var encryptedUrl='/TEMPLATE_PROJECT_ID/TEMPLATE_ENCRYPTED_DATA';widget.setEncryptedURL(encryptedUrl).run();</script></body></html>",
-            config,
-        }
+        Self { config }
     }
 
+    /// Renders the page in a single pass. Every config value is either HTML-escaped in
+    /// attributes or JSON-encoded in the script, so customer data can't break out of its place.
     pub fn to_html(&self) -> String {
-        self.replace_template_variables()
-    }
-
-    fn replace_template_variables(&self) -> String {
-        // todo: allocate only once
-        let mut html_template = self.html_template.replace("TEMPLATE_PAYMENT_HOST", &self.config.host);
-        html_template = html_template.replace("TEMPLATE_PAYMENT_ID", &self.config.payment_id);
-        html_template = html_template.replace("TEMPLATE_PAYMENT_AMOUNT", &self.config.payment_amount.to_string());
-        html_template = html_template.replace("TEMPLATE_PAYMENT_CURRENCY", &self.config.payment_currency);
-        html_template = html_template.replace("TEMPLATE_PROJECT_ID", &self.config.project_id.to_string());
-        html_template = html_template.replace("TEMPLATE_CUSTOMER_ID", &self.config.customer_id);
-        html_template = html_template.replace("TEMPLATE_CUSTOMER_FIRST_NAME", &self.config.customer_first_name);
-        html_template = html_template.replace("TEMPLATE_CUSTOMER_LAST_NAME", &self.config.customer_last_name);
-        html_template = html_template.replace("TEMPLATE_CUSTOMER_EMAIL", &self.config.customer_email);
-        html_template = html_template.replace("TEMPLATE_SIGNATURE", &self.config.signature);
-        html_template = html_template.replace("TEMPLATE_ENCRYPTED_DATA", &self.config.encrypted_data);
-
-        html_template
+        let config = &self.config;
+        let widget_config = WidgetConfig {
+            payment_id: &config.payment_id,
+            payment_amount: config.payment_amount,
+            payment_currency: config.payment_currency.code(),
+            project_id: config.project_id,
+            customer_id: &config.customer_id,
+            customer_first_name: &config.customer_first_name,
+            customer_last_name: &config.customer_last_name,
+            customer_email: &config.customer_email,
+            target_element: "widget-container",
+            signature: &config.signature,
+        };
+        let encrypted_url = format!("/{}/{}", config.project_id, config.encrypted_data);
+        let host = escape_html(&config.host);
+
+        format!(
+            "<html><head><link rel=\"stylesheet\" href=\"{host}/shared/merchant.css\"><script src=\"{host}/shared/merchant.js\"></script></head><body><div class=\"container\"><div class=\"cart-info\"></div><div id=\"widget-container\"></div></div><script type=\"text/javascript\">var configObj={config};var widget=EPayWidget.create(configObj);var url=widget.buildUrl();var encryptedUrl={encrypted_url};widget.setEncryptedURL(encryptedUrl).run();</script></body></html>",
+            host = host,
+            config = to_script_json(&widget_config),
+            encrypted_url = to_script_json(&encrypted_url),
+        )
+    }
+}
+
+/// Escapes a value for an HTML attribute or text.
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for char in value.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+/// JSON that is safe inside a `<script>` element: it can't close the element or start
+/// a comment, and JS line separators are escaped.
+fn to_script_json<T: Serialize>(value: &T) -> String {
+    let json = serde_json::to_string(value).expect("widget config is always serializable");
+    let mut escaped = String::with_capacity(json.len());
+
+    for char in json.chars() {
+        match char {
+            '<' => escaped.push_str("\\u003c"),
+            '>' => escaped.push_str("\\u003e"),
+            '&' => escaped.push_str("\\u0026"),
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::currencies::Currency;
+    use crate::rest::healthcheck::models::PaymentPageConfig;
+    use crate::rest::payment_page::PaymentPage;
+
+    fn config() -> PaymentPageConfig {
+        PaymentPageConfig {
+            host: "https://paymentpage.example.com".to_string(),
+            signature: "sign+/=".to_string(),
+            encrypted_data: "encrypted".to_string(),
+            payment_id: "payment_id".to_string(),
+            payment_amount: 1000,
+            payment_currency: Currency::USD,
+            project_id: 1000,
+            customer_id: "customer_id".to_string(),
+            customer_first_name: "John".to_string(),
+            customer_last_name: "Doe".to_string(),
+            customer_email: "john@example.com".to_string(),
+        }
+    }
+
+    /// Content of the inline script with the widget config.
+    fn script(html: &str) -> &str {
+        let start = html.find("<script type=\"text/javascript\">").unwrap();
+        let end = html.rfind("</script>").unwrap();
+
+        &html[start..end]
+    }
+
+    #[test]
+    fn renders_config() {
+        let html = PaymentPage::new(config()).to_html();
+
+        assert!(html.contains("<script src=\"https://paymentpage.example.com/shared/merchant.js\">"));
+        assert!(html.contains(r#""payment_id":"payment_id","payment_amount":1000,"payment_currency":"USD","project_id":1000"#));
+        assert!(html.contains(r#""signature":"sign+/=""#));
+        assert!(html.contains(r#"var encryptedUrl="/1000/encrypted";"#));
+    }
+
+    #[test]
+    fn malicious_names_are_escaped() {
+        let mut config = config();
+        config.customer_first_name = "'};alert(1);//".to_string();
+        config.customer_last_name = "\"};alert(2);//".to_string();
+        config.customer_email = "</script><script>alert(3)</script>".to_string();
+        config.customer_id = "TEMPLATE_SIGNATURE\u{2028}<!--".to_string();
+
+        let html = PaymentPage::new(config.clone()).to_html();
+        let script = script(&html);
+
+        assert!(!script.contains("</script"));
+        assert!(!script.contains("<!--"));
+        assert!(!script.contains('\u{2028}'));
+        assert_eq!(html.matches("alert(").count(), 3);
+        assert!(script.contains(r#""customer_first_name":"'};alert(1);//""#));
+        assert!(script.contains(r#""customer_last_name":"\"};alert(2);//""#));
+        assert!(script.contains(r#""customer_id":"TEMPLATE_SIGNATURE\u2028\u003c!--""#));
+
+        let start = script.find('{').unwrap();
+        let end = script.find(";var widget").unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&script[start..end]).unwrap();
+
+        assert_eq!(parsed["customer_first_name"], config.customer_first_name);
+        assert_eq!(parsed["customer_email"], config.customer_email);
+        assert_eq!(parsed["customer_id"], config.customer_id);
+    }
+
+    #[test]
+    fn malicious_host_is_escaped() {
+        let mut config = config();
+        config.host = "https://x\"><script>alert(1)</script>".to_string();
+
+        let html = PaymentPage::new(config).to_html();
+
+        assert!(!html.contains("<script>alert"));
+        assert!(html.contains("href=\"https://x&quot;&gt;&lt;script&gt;alert(1)&lt;/script&gt;/shared/merchant.css\""));
     }
 }
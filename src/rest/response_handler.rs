@@ -1,66 +1,37 @@
-use error_chain::bail;
 use reqwest::StatusCode;
-use reqwest::Response;
 use serde::de::DeserializeOwned;
-use crate::rest::errors::Error;
+use crate::rest::errors::{MonetixContentError, MonetixError};
+use crate::rest::transport::MonetixHttpResponse;
 
-
-pub async fn handle<T: DeserializeOwned>(
-    response: Response,
-    request_json: Option<String>,
+pub fn handle<T: DeserializeOwned>(
+    response: MonetixHttpResponse,
     request_url: &str,
-) -> Result<T, Error> {
-    match response.status() {
-        StatusCode::OK => {
-            let json: Result<String, _> = response.text().await;
-            let Ok(json) = json else {
-                bail!("Failed to read response body. Url {}", request_url);
-            };
-
-            let body: Result<T, _> = serde_json::from_str(&json);
-            if let Err(err) = body {
-                bail!(
-                        "Url {}. Failed to deserialize body {:?}: {}",
-                        request_url,
-                        err,
-                        json
-                    );
-            }
+) -> Result<T, MonetixError> {
+    let status = response.status;
+    let body = response.body;
 
-            Ok(body.unwrap())
+    match status {
+        StatusCode::OK | StatusCode::CREATED => {
+            serde_json::from_str(&body).map_err(|source| MonetixError::Deserialize {
+                url: request_url.to_string(),
+                body,
+                source,
+            })
         }
-        StatusCode::CREATED => {
-            let json: Result<String, _> = response.text().await;
-            let Ok(json) = json else {
-                bail!("Failed to read response body");
-            };
-            let body: Result<T, _> = serde_json::from_str(&json);
-            if let Err(err) = body {
-                bail!("Failed to deserialize body {:?}: {}", err, json);
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => {
+            match serde_json::from_str::<MonetixContentError>(&body) {
+                Ok(error) => Err(MonetixError::Validation(Box::new(error))),
+                Err(_) => Err(MonetixError::Http {
+                    status,
+                    url: request_url.to_string(),
+                    body,
+                }),
             }
-
-            Ok(body.unwrap())
-        }
-        StatusCode::INTERNAL_SERVER_ERROR => {
-            bail!("Internal Server Error {}", request_url,);
-        }
-        StatusCode::SERVICE_UNAVAILABLE => {
-            bail!("Service Unavailable {}", request_url,);
-        }
-        StatusCode::UNAUTHORIZED => {
-            bail!("Unauthorized {}", request_url);
-        }
-        StatusCode::BAD_REQUEST => {
-            let error = response.text().await?;
-            bail!(format!(
-                    "Received bad request status. Url: {}. Request: {:?}. Response: {:?}",
-                    request_url, request_json, error
-                ));
-        }
-        s => {
-            let error = response.text().await?;
-
-            bail!(format!("Received response code: {s:?} error: {error:?}"));
         }
+        status => Err(MonetixError::Http {
+            status,
+            url: request_url.to_string(),
+            body,
+        }),
     }
-}
\ No newline at end of file
+}
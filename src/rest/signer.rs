@@ -3,6 +3,7 @@ use base64::Engine;
 use ring::hmac;
 use serde::Serialize;
 use serde_json::Value;
+use crate::rest::callback::MonetixCallbackModel;
 
 #[derive(Debug, Clone)]
 pub struct MonetixSigner {
@@ -19,6 +20,22 @@ impl MonetixSigner {
 
 pub trait MonetixRequest: Serialize {}
 
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyError {
+    /// The body is not a JSON object or can't be converted into a sign string.
+    #[error("Invalid callback json: {0}")]
+    InvalidJson(String),
+    /// The body has no top-level `signature` string.
+    #[error("Callback signature is missing")]
+    MissingSignature,
+    /// The signature doesn't match the body.
+    #[error("Callback signature is invalid")]
+    InvalidSignature,
+    /// The signature is valid but the body doesn't match `MonetixCallbackModel`.
+    #[error("Invalid callback model: {0}")]
+    InvalidModel(String),
+}
+
 impl MonetixSigner {
     pub fn generate_sign<T: MonetixRequest>(&self, data: &T) -> Result<String, String> {
         let data = serde_json::to_string(data);
@@ -46,6 +63,34 @@ impl MonetixSigner {
         Ok(self.sign_str(&data))
     }
 
+    /// Checks the signature of a raw callback body and returns the parsed callback only if it's valid.
+    /// The comparison is done in constant time.
+    pub fn verify_callback(&self, raw_body: &str) -> Result<MonetixCallbackModel, VerifyError> {
+        let value: Value = serde_json::from_str(raw_body)
+            .map_err(|err| VerifyError::InvalidJson(err.to_string()))?;
+
+        let Some(signature) = value.get("signature").and_then(|v| v.as_str()) else {
+            return Err(VerifyError::MissingSignature);
+        };
+
+        self.verify(raw_body, signature)?;
+
+        serde_json::from_value(value).map_err(|err| VerifyError::InvalidModel(err.to_string()))
+    }
+
+    /// Checks that the signature matches the json data. Signature fields inside the data are ignored.
+    /// The comparison is done in constant time.
+    pub fn verify(&self, data: &str, signature: &str) -> Result<(), VerifyError> {
+        let Ok(signature) = general_purpose::STANDARD.decode(signature) else {
+            return Err(VerifyError::InvalidSignature);
+        };
+
+        let data = MonetixSigner::convert_to_sign_string(data).map_err(VerifyError::InvalidJson)?;
+        let key = hmac::Key::new(hmac::HMAC_SHA512, self.secret_key.as_bytes());
+
+        hmac::verify(&key, data.as_bytes(), &signature).map_err(|_| VerifyError::InvalidSignature)
+    }
+
     pub fn convert_to_sign_string(data: &str) -> Result<String, String> {
         let parsed_value: Result<Value, _> = serde_json::from_str(data);
 
@@ -106,10 +151,6 @@ impl MonetixSigner {
                 let mut parts = Vec::with_capacity(value.len());
 
                 for (inner_key, inner_value) in value.iter() {
-                    println!("{}", key);
-                    println!("{}", inner_key);
-                    println!("{}", inner_value);
-
                     if let Some(part) =
                         MonetixSigner::key_value_to_string(&format!("{}:{}", key, inner_key), inner_value)
                     {
@@ -129,7 +170,10 @@ impl MonetixSigner {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::rest::gate::models::{MonetixCreateInvoicePaymentRequest, MonetixCustomerModel, MonetixGeneralModel, MonetixPaymentModel, MonetixReturnUrlModel};
+    use crate::rest::currencies::Currency;
+    use crate::rest::money::Money;
+    use crate::rest::test_fixtures::{signed_callback, CALLBACK_JSON};
+    use crate::rest::gate::models::{CardOperationType, MonetixCreateInvoicePaymentRequest, MonetixCustomerModel, MonetixGeneralModel, MonetixPaymentModel, MonetixReturnUrlModel};
 
     #[test]
     fn correct_signing_algorithm() {
@@ -230,8 +274,7 @@ mod tests {
                 //billing: None,
             },
             payment: MonetixPaymentModel {
-                amount: 1000,
-                currency: "USD".to_string(),
+                money: Money::from_minor(1000, Currency::USD),
                 description: Some("description".to_string()),
                 extra_param: Some("extra_param".to_string()),
                 best_before: "3467".to_string(),
@@ -243,7 +286,7 @@ mod tests {
                 decline: None,
                 return_url: None,
             },
-            card_operation_type: "sale".to_string(),
+            card_operation_type: CardOperationType::Sale,
             send_email: false,
         };
         let signer = MonetixSigner {
@@ -254,6 +297,51 @@ mod tests {
         //assert_eq!(sign, "Y+5QpmclSM9RATg0IoXaOFYSPmpCgd+IV2k7jK2gHHChk/sL7H5dcjjR1gZ8gNV0vz9sNIqrOpQo/HcInJ1iTQ==");
     }
 
+    #[test]
+    fn verify_callback_valid() {
+        let signer = MonetixSigner::new("123abc123abc");
+        let body = signed_callback(&signer, CALLBACK_JSON);
+        let callback = signer.verify_callback(&body).unwrap();
+
+        assert_eq!(callback.payment.id, "payment_id");
+        assert_eq!(callback.project_id, 1000);
+    }
+
+    #[test]
+    fn verify_callback_malformed_display_data() {
+        let signer = MonetixSigner::new("123abc123abc");
+        let json = CALLBACK_JSON.replace(r#""project_id""#, r#""display_data": {"qr": 1}, "project_id""#);
+        let callback = signer.verify_callback(&signed_callback(&signer, &json)).unwrap();
+
+        assert!(callback.display_data.is_empty());
+    }
+
+    #[test]
+    fn verify_callback_tampered() {
+        let signer = MonetixSigner::new("123abc123abc");
+        let body = signed_callback(&signer, CALLBACK_JSON).replace("\"success\"", "\"decline\"");
+        let result = signer.verify_callback(&body);
+
+        assert!(matches!(result, Err(VerifyError::InvalidSignature)));
+    }
+
+    #[test]
+    fn verify_callback_wrong_key() {
+        let signer = MonetixSigner::new("123abc123abc");
+        let body = signed_callback(&MonetixSigner::new("other"), CALLBACK_JSON);
+        let result = signer.verify_callback(&body);
+
+        assert!(matches!(result, Err(VerifyError::InvalidSignature)));
+    }
+
+    #[test]
+    fn verify_callback_missing_signature() {
+        let signer = MonetixSigner::new("123abc123abc");
+        let result = signer.verify_callback(r#"{"project_id": 1000}"#);
+
+        assert!(matches!(result, Err(VerifyError::MissingSignature)));
+    }
+
     #[test]
     fn test_array_obj_1() {
         let json = r#"
@@ -0,0 +1,583 @@
+//! Local stand-in for the Monetix gate, healthcheck and payment page to run
+//! end-to-end flows in tests without the real service.
+
+use crate::rest::gate::endpoints::MonetixGateEndpoint;
+use crate::rest::gate::payout_method::PayoutMethod;
+use crate::rest::signer::MonetixSigner;
+use axum::extract::State;
+use axum::http::{StatusCode, Uri};
+use axum::response::Html;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// Result of a simulated payment, reported with a signed callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulatedOutcome {
+    Success,
+    Decline,
+    /// Payment stops in `awaiting 3ds result` and the callback contains the `acs` object.
+    ThreeDs,
+    /// Sends the callback of the inner outcome after the delay.
+    Delayed(Duration, Box<SimulatedOutcome>),
+    /// Accepts the request without sending a callback.
+    NoCallback,
+}
+
+/// Request received by the simulator.
+#[derive(Debug, Clone)]
+pub struct SimulatedRequest {
+    pub path: String,
+    pub body: String,
+}
+
+struct SimulatorState {
+    project_id: u32,
+    signer: MonetixSigner,
+    public_host: String,
+    callback_url: Option<String>,
+    http_client: reqwest::Client,
+    default_outcome: Mutex<SimulatedOutcome>,
+    outcomes: Mutex<HashMap<String, SimulatedOutcome>>,
+    requests: Mutex<Vec<SimulatedRequest>>,
+    payments: Mutex<HashMap<String, SimulatedPayment>>,
+}
+
+/// Payment as reported by `/v2/payment/status`.
+struct SimulatedPayment {
+    payment_type: &'static str,
+    status: String,
+    sum: Value,
+    operations: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OperationKind {
+    Sale,
+    Payout,
+    Refund,
+}
+
+impl OperationKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            OperationKind::Sale => "sale",
+            OperationKind::Payout => "payout",
+            OperationKind::Refund => "refund",
+        }
+    }
+}
+
+pub struct MonetixSimulator {
+    address: SocketAddr,
+    state: Arc<SimulatorState>,
+    server: JoinHandle<()>,
+}
+
+impl MonetixSimulator {
+    /// Starts the simulator on a random local port. Callbacks are sent to `callback_url`
+    /// or, if it's not set, to `general.merchant_callback_url` of the request.
+    pub async fn start(
+        project_id: u32,
+        secret_key: impl Into<String>,
+        callback_url: Option<String>,
+    ) -> std::io::Result<Self> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(SimulatorState {
+            project_id,
+            signer: MonetixSigner::new(secret_key),
+            public_host: address.to_string(),
+            callback_url,
+            http_client: reqwest::Client::new(),
+            default_outcome: Mutex::new(SimulatedOutcome::Success),
+            outcomes: Mutex::new(HashMap::new()),
+            requests: Mutex::new(Vec::new()),
+            payments: Mutex::new(HashMap::new()),
+        });
+        let payout_methods = [
+            PayoutMethod::Card,
+            PayoutMethod::Spei,
+            PayoutMethod::Nequi,
+            PayoutMethod::ColombiaBankTransfer,
+            PayoutMethod::Pse,
+            PayoutMethod::Pix,
+        ];
+        let mut router = Router::new()
+            .route(&String::from(&MonetixGateEndpoint::PaymentStatus), post(handle_status))
+            .route(&String::from(&MonetixGateEndpoint::CardRefund), post(handle_refund))
+            .route("/v2/payment/{*path}", post(handle_sale))
+            .route("/g2", get(handle_payment_host))
+            .route("/payment", get(handle_payment_page))
+            .route("/{project_id}/{*data}", get(handle_payment_page));
+
+        for payout_method in payout_methods {
+            router = router.route(payout_method.path(), post(handle_payout));
+        }
+
+        let router = router.with_state(state.clone());
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+
+        Ok(Self {
+            address,
+            state,
+            server,
+        })
+    }
+
+    /// Base url to use as the gate host or the healthcheck url. `/g2` answers with the
+    /// same address, so the healthcheck client needs `with_payment_host_scheme("http")`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Outcome for payments without a scripted outcome. Default is success.
+    pub fn set_default_outcome(&self, outcome: SimulatedOutcome) {
+        *self.state.default_outcome.lock().unwrap() = outcome;
+    }
+
+    pub fn script_outcome(&self, payment_id: impl Into<String>, outcome: SimulatedOutcome) {
+        self.state
+            .outcomes
+            .lock()
+            .unwrap()
+            .insert(payment_id.into(), outcome);
+    }
+
+    /// All requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<SimulatedRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MonetixSimulator {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+async fn handle_sale(State(state): State<Arc<SimulatorState>>, uri: Uri, body: String) -> (StatusCode, Json<Value>) {
+    handle_operation(state, uri.path(), body, OperationKind::Sale).await
+}
+
+async fn handle_payout(State(state): State<Arc<SimulatorState>>, uri: Uri, body: String) -> (StatusCode, Json<Value>) {
+    handle_operation(state, uri.path(), body, OperationKind::Payout).await
+}
+
+async fn handle_refund(State(state): State<Arc<SimulatorState>>, uri: Uri, body: String) -> (StatusCode, Json<Value>) {
+    handle_operation(state, uri.path(), body, OperationKind::Refund).await
+}
+
+async fn handle_status(State(state): State<Arc<SimulatorState>>, uri: Uri, body: String) -> (StatusCode, Json<Value>) {
+    let (_, payment_id) = match accept_request(&state, uri.path(), &body) {
+        Ok(accepted) => accepted,
+        Err(response) => return response,
+    };
+    let payments = state.payments.lock().unwrap();
+
+    let Some(payment) = payments.get(&payment_id) else {
+        return error_response("3061", "Payment not found");
+    };
+
+    (
+        StatusCode::OK,
+        Json(json!({
+            "project_id": state.project_id,
+            "payment": {
+                "id": payment_id,
+                "type": payment.payment_type,
+                "status": payment.status,
+                "sum": payment.sum,
+            },
+            "operations": payment.operations,
+        })),
+    )
+}
+
+/// Records the request and checks its signature, returns the parsed request and the payment id.
+fn accept_request(
+    state: &SimulatorState,
+    path: &str,
+    body: &str,
+) -> Result<(Value, String), (StatusCode, Json<Value>)> {
+    state.requests.lock().unwrap().push(SimulatedRequest {
+        path: path.to_string(),
+        body: body.to_string(),
+    });
+
+    let request: Value = match serde_json::from_str(body) {
+        Ok(request) => request,
+        Err(err) => return Err(error_response("1", &format!("Invalid json: {}", err))),
+    };
+    let signature = request["general"]["signature"].as_str().unwrap_or_default();
+
+    if state.signer.verify(body, signature).is_err() {
+        return Err(error_response("1101", "Invalid signature"));
+    }
+
+    let Some(payment_id) = request["general"]["payment_id"].as_str().map(str::to_string) else {
+        return Err(error_response("1102", "general.payment_id is required"));
+    };
+
+    Ok((request, payment_id))
+}
+
+async fn handle_operation(
+    state: Arc<SimulatorState>,
+    path: &str,
+    body: String,
+    kind: OperationKind,
+) -> (StatusCode, Json<Value>) {
+    let (request, payment_id) = match accept_request(&state, path, &body) {
+        Ok(accepted) => accepted,
+        Err(response) => return response,
+    };
+
+    if kind == OperationKind::Refund && !state.payments.lock().unwrap().contains_key(&payment_id) {
+        return error_response("3061", "Payment not found");
+    }
+
+    let request_id = Uuid::new_v4().to_string();
+    let outcome = state
+        .outcomes
+        .lock()
+        .unwrap()
+        .get(&payment_id)
+        .cloned()
+        .unwrap_or_else(|| state.default_outcome.lock().unwrap().clone());
+    let callback_url = state.callback_url.clone().or_else(|| {
+        request["general"]["merchant_callback_url"]
+            .as_str()
+            .map(str::to_string)
+    });
+    let sum = json!({
+        "amount": request["payment"]["amount"],
+        "currency": request["payment"]["currency"],
+    });
+    let operation = {
+        let mut payments = state.payments.lock().unwrap();
+        let payment = payments.entry(payment_id.clone()).or_insert_with(|| SimulatedPayment {
+            payment_type: if kind == OperationKind::Payout { "payout" } else { "purchase" },
+            status: "processing".to_string(),
+            sum: sum.clone(),
+            operations: Vec::new(),
+        });
+        let operation = json!({
+            "id": payment.operations.len() + 1,
+            "request_id": request_id,
+            "status": "processing",
+            "type": kind.as_str(),
+            "sum_initial": sum,
+        });
+        payment.operations.push(operation.clone());
+
+        operation
+    };
+
+    let operation = OperationData {
+        kind,
+        request,
+        payment_id: payment_id.clone(),
+        operation,
+    };
+
+    let project_id = state.project_id;
+
+    tokio::spawn(async move { finish_operation(state, callback_url, operation, outcome).await });
+
+    (
+        StatusCode::OK,
+        Json(json!({
+            "status": "success",
+            "request_id": request_id,
+            "project_id": project_id,
+            "payment_id": payment_id,
+        })),
+    )
+}
+
+async fn handle_payment_host(State(state): State<Arc<SimulatorState>>) -> String {
+    state.public_host.clone()
+}
+
+async fn handle_payment_page() -> Html<&'static str> {
+    Html("<html><body>Monetix simulator payment page</body></html>")
+}
+
+fn error_response(code: &str, message: &str) -> (StatusCode, Json<Value>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(json!({
+            "status": "error",
+            "code": code,
+            "message": message,
+        })),
+    )
+}
+
+struct OperationData {
+    kind: OperationKind,
+    request: Value,
+    payment_id: String,
+    operation: Value,
+}
+
+/// Applies the outcome to the payment and sends the callback if there is a callback url.
+async fn finish_operation(
+    state: Arc<SimulatorState>,
+    callback_url: Option<String>,
+    mut data: OperationData,
+    mut outcome: SimulatedOutcome,
+) {
+    while let SimulatedOutcome::Delayed(delay, inner) = outcome {
+        tokio::time::sleep(delay).await;
+        outcome = *inner;
+    }
+
+    let is_partial_refund = !data.request["payment"]["amount"].is_null();
+    let (operation_status, acs) = match outcome {
+        SimulatedOutcome::Success => ("success", None),
+        SimulatedOutcome::Decline => ("decline", None),
+        SimulatedOutcome::ThreeDs => (
+            "awaiting 3ds result",
+            Some(json!({
+                "acs_url": format!("http://{}/acs", state.public_host),
+                "md": Uuid::new_v4().to_string(),
+                "pa_req": "simulated_pa_req",
+            })),
+        ),
+        SimulatedOutcome::NoCallback | SimulatedOutcome::Delayed(..) => return,
+    };
+    data.operation["status"] = Value::String(operation_status.to_string());
+
+    let (payment_type, payment_status, sum) = {
+        let mut payments = state.payments.lock().unwrap();
+        let Some(payment) = payments.get_mut(&data.payment_id) else {
+            return;
+        };
+
+        payment.status = match (data.kind, operation_status) {
+            (OperationKind::Refund, "success") if is_partial_refund => "partially refunded".to_string(),
+            (OperationKind::Refund, "success") => "refunded".to_string(),
+            // a declined refund leaves the payment as it was
+            (OperationKind::Refund, _) => payment.status.clone(),
+            (_, status) => status.to_string(),
+        };
+
+        if let Some(operation) = payment
+            .operations
+            .iter_mut()
+            .find(|operation| operation["request_id"] == data.operation["request_id"])
+        {
+            *operation = data.operation.clone();
+        }
+
+        (payment.payment_type, payment.status.clone(), payment.sum.clone())
+    };
+
+    let Some(callback_url) = callback_url else {
+        return;
+    };
+
+    let mut callback = json!({
+        "customer": {"id": data.request["customer"]["id"].as_str().unwrap_or("customer")},
+        "payment": {
+            "id": data.payment_id,
+            "status": payment_status,
+            "type": payment_type,
+            "sum": sum,
+        },
+        "operation": data.operation,
+        "project_id": state.project_id,
+    });
+
+    if let Some(acs) = acs {
+        callback["acs"] = acs;
+    }
+
+    let Ok(signature) = state.signer.generate_sign_from_str(&callback.to_string()) else {
+        return;
+    };
+    callback["signature"] = Value::String(signature);
+
+    let _ = state
+        .http_client
+        .post(&callback_url)
+        .header("content-type", "application/json")
+        .body(callback.to_string())
+        .send()
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::callback::{MonetixCallbackModel, MonetixThreeDsAction};
+    use crate::rest::currencies::Currency;
+    use crate::rest::errors::MonetixError;
+    use crate::rest::gate::models::MonetixPaymentModel;
+    use crate::rest::gate::refund::MonetixRefundPaymentModel;
+    use crate::rest::gate::rest_client::MonetixGateRestClient;
+    use crate::rest::healthcheck::models::GetPaymentPageArgs;
+    use crate::rest::healthcheck::rest_client::MonetixHealthcheckRestClient;
+    use crate::rest::money::Money;
+    use crate::rest::signer::MonetixSigner;
+    use crate::rest::simulator::{MonetixSimulator, SimulatedOutcome};
+    use crate::rest::status::PaymentStatus;
+    use crate::rest::webhook::test_server::spawn_callback_server;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    const SECRET_KEY: &str = "secret";
+
+    fn payment() -> MonetixPaymentModel {
+        MonetixPaymentModel {
+            money: Money::from_minor(1000, Currency::USD),
+            description: None,
+            extra_param: None,
+            best_before: "2050-01-01T00:00:00+00:00".to_string(),
+            moto_type: 0,
+        }
+    }
+
+    async fn recv(receiver: &mut mpsc::UnboundedReceiver<MonetixCallbackModel>) -> MonetixCallbackModel {
+        tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn invoice_flow_with_outcomes() {
+        let (callback_url, mut receiver) = spawn_callback_server(MonetixSigner::new(SECRET_KEY)).await;
+        let simulator = MonetixSimulator::start(1000, SECRET_KEY, Some(callback_url)).await.unwrap();
+        simulator.script_outcome("declined", SimulatedOutcome::Decline);
+        simulator.script_outcome("three_ds", SimulatedOutcome::ThreeDs);
+        let client = MonetixGateRestClient::builder(1000, SECRET_KEY)
+            .host(simulator.url())
+            .build()
+            .unwrap();
+
+        client.create_invoice_payment("paid", "customer", payment()).await.unwrap();
+        let callback = recv(&mut receiver).await;
+        assert_eq!(callback.payment.id, "paid");
+        assert_eq!(callback.payment.status, PaymentStatus::Success);
+
+        client.create_invoice_payment("declined", "customer", payment()).await.unwrap();
+        let callback = recv(&mut receiver).await;
+        assert_eq!(callback.payment.status, PaymentStatus::Decline);
+
+        client.create_invoice_payment("three_ds", "customer", payment()).await.unwrap();
+        let callback = recv(&mut receiver).await;
+        assert_eq!(callback.payment.status, PaymentStatus::AwaitingThreeDsResult);
+        assert!(matches!(callback.three_ds_action(), Some(MonetixThreeDsAction::Acs(_))));
+
+        assert_eq!(simulator.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn delayed_callback() {
+        let (callback_url, mut receiver) = spawn_callback_server(MonetixSigner::new(SECRET_KEY)).await;
+        let simulator = MonetixSimulator::start(1000, SECRET_KEY, Some(callback_url)).await.unwrap();
+        simulator.set_default_outcome(SimulatedOutcome::Delayed(
+            Duration::from_millis(50),
+            Box::new(SimulatedOutcome::Success),
+        ));
+        let client = MonetixGateRestClient::builder(1000, SECRET_KEY)
+            .host(simulator.url())
+            .build()
+            .unwrap();
+
+        client.create_invoice_payment("delayed", "customer", payment()).await.unwrap();
+
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(recv(&mut receiver).await.payment.id, "delayed");
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_signature() {
+        let simulator = MonetixSimulator::start(1000, SECRET_KEY, None).await.unwrap();
+        let client = MonetixGateRestClient::builder(1000, "wrong secret")
+            .host(simulator.url())
+            .build()
+            .unwrap();
+
+        let result = client.create_invoice_payment("payment", "customer", payment()).await;
+
+        assert!(matches!(result, Err(MonetixError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn payment_host() {
+        let simulator = MonetixSimulator::start(1000, SECRET_KEY, None).await.unwrap();
+        let client = MonetixHealthcheckRestClient::new(1000, SECRET_KEY, "key", simulator.url());
+
+        let host = client.get_payment_host().await.unwrap();
+
+        assert_eq!(host, format!("https://{}", simulator.url().trim_start_matches("http://")));
+    }
+
+    #[tokio::test]
+    async fn payment_page_flow() {
+        let simulator = MonetixSimulator::start(1000, SECRET_KEY, None).await.unwrap();
+        let client = MonetixHealthcheckRestClient::new(1000, SECRET_KEY, "key", simulator.url())
+            .with_payment_host_scheme("http");
+        let args = GetPaymentPageArgs {
+            payment_id: "payment_id".to_string(),
+            payment_amount: 1000,
+            payment_currency: Currency::USD,
+            project_id: 1000,
+            customer_id: "customer".to_string(),
+            customer_first_name: "John".to_string(),
+            customer_last_name: "Doe".to_string(),
+            customer_email: "john@example.com".to_string(),
+        };
+
+        let url = client.get_payment_url(args).await.unwrap();
+        let response = reqwest::get(&url).await.unwrap();
+
+        assert!(url.starts_with(&format!("{}/1000/", simulator.url())));
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        assert!(response.text().await.unwrap().contains("simulator payment page"));
+    }
+
+    #[tokio::test]
+    async fn payment_status() {
+        let (callback_url, mut receiver) = spawn_callback_server(MonetixSigner::new(SECRET_KEY)).await;
+        let simulator = MonetixSimulator::start(1000, SECRET_KEY, Some(callback_url)).await.unwrap();
+        let client = MonetixGateRestClient::builder(1000, SECRET_KEY)
+            .host(simulator.url())
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            client.get_payment_status("unknown").await,
+            Err(MonetixError::Validation(_))
+        ));
+
+        client.create_invoice_payment("paid", "customer", payment()).await.unwrap();
+        recv(&mut receiver).await;
+
+        let status = client.get_payment_status("paid").await.unwrap();
+        assert_eq!(status.payment.id, "paid");
+        assert_eq!(status.payment.status, PaymentStatus::Success);
+
+        client
+            .refund("paid", MonetixRefundPaymentModel::partial(Money::from_minor(100, Currency::USD), "refund"))
+            .await
+            .unwrap();
+        let callback = recv(&mut receiver).await;
+        assert_eq!(callback.payment.status, PaymentStatus::PartiallyRefunded);
+
+        let status = client.get_payment_status("paid").await.unwrap();
+        assert_eq!(status.payment.status, PaymentStatus::PartiallyRefunded);
+        assert_eq!(status.operations.unwrap().len(), 2);
+    }
+}
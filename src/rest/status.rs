@@ -0,0 +1,217 @@
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+            /// Value not known to this version of the connector.
+            Unknown(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)+
+                    _ => $name::Unknown(value.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                $name::from(value.as_str())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.as_str().to_string()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// Status of the payment as a whole.
+    pub enum PaymentStatus {
+        Processing => "processing",
+        AwaitingThreeDsResult => "awaiting 3ds result",
+        AwaitingRedirectResult => "awaiting redirect result",
+        AwaitingCustomer => "awaiting customer",
+        AwaitingClarification => "awaiting clarification",
+        AwaitingCapture => "awaiting capture",
+        AwaitingConfirmation => "awaiting confirmation",
+        ExternalProcessing => "external processing",
+        InvoiceSent => "invoice sent",
+        Success => "success",
+        Decline => "decline",
+        Refunded => "refunded",
+        PartiallyRefunded => "partially refunded",
+        Reversed => "reversed",
+        PartiallyReversed => "partially reversed",
+        Cancelled => "cancelled",
+        Expired => "expired",
+        Error => "error",
+        InternalError => "internal error",
+        ExternalError => "external error",
+    }
+}
+
+impl PaymentStatus {
+    /// The payment won't change its status without a new operation (refund, reversal).
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            PaymentStatus::Success
+                | PaymentStatus::Decline
+                | PaymentStatus::Refunded
+                | PaymentStatus::PartiallyRefunded
+                | PaymentStatus::Reversed
+                | PaymentStatus::PartiallyReversed
+                | PaymentStatus::Cancelled
+                | PaymentStatus::Expired
+                | PaymentStatus::Error
+                | PaymentStatus::InternalError
+                | PaymentStatus::ExternalError
+        )
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, PaymentStatus::Success)
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            PaymentStatus::Processing
+                | PaymentStatus::AwaitingThreeDsResult
+                | PaymentStatus::AwaitingRedirectResult
+                | PaymentStatus::AwaitingCustomer
+                | PaymentStatus::AwaitingClarification
+                | PaymentStatus::AwaitingCapture
+                | PaymentStatus::AwaitingConfirmation
+                | PaymentStatus::ExternalProcessing
+                | PaymentStatus::InvoiceSent
+        )
+    }
+}
+
+string_enum! {
+    /// Status of a single operation within the payment.
+    pub enum OperationStatus {
+        Processing => "processing",
+        AwaitingThreeDsResult => "awaiting 3ds result",
+        AwaitingRedirectResult => "awaiting redirect result",
+        AwaitingCustomer => "awaiting customer",
+        AwaitingClarification => "awaiting clarification",
+        Success => "success",
+        Decline => "decline",
+        Error => "error",
+        InternalError => "internal error",
+        ExternalError => "external error",
+    }
+}
+
+impl OperationStatus {
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            OperationStatus::Success
+                | OperationStatus::Decline
+                | OperationStatus::Error
+                | OperationStatus::InternalError
+                | OperationStatus::ExternalError
+        )
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, OperationStatus::Success)
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            OperationStatus::Processing
+                | OperationStatus::AwaitingThreeDsResult
+                | OperationStatus::AwaitingRedirectResult
+                | OperationStatus::AwaitingCustomer
+                | OperationStatus::AwaitingClarification
+        )
+    }
+}
+
+string_enum! {
+    pub enum OperationType {
+        Sale => "sale",
+        Auth => "auth",
+        Capture => "capture",
+        Cancel => "cancel",
+        Refund => "refund",
+        Reversal => "reversal",
+        Payout => "payout",
+        Recurring => "recurring",
+        AccountVerification => "account verification",
+        Chargeback => "chargeback",
+    }
+}
+
+string_enum! {
+    pub enum PaymentType {
+        Purchase => "purchase",
+        Payout => "payout",
+        Recurring => "recurring",
+        AccountVerification => "account verification",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::status::{OperationType, PaymentStatus};
+
+    #[test]
+    fn payment_status_from_json() {
+        let status: PaymentStatus = serde_json::from_str("\"awaiting 3ds result\"").unwrap();
+
+        assert_eq!(status, PaymentStatus::AwaitingThreeDsResult);
+        assert!(status.is_pending());
+        assert!(!status.is_final());
+    }
+
+    #[test]
+    fn payment_status_unknown() {
+        let status: PaymentStatus = serde_json::from_str("\"something new\"").unwrap();
+
+        assert_eq!(status, PaymentStatus::Unknown("something new".to_string()));
+        assert!(!status.is_pending());
+        assert!(!status.is_final());
+        assert_eq!(serde_json::to_string(&status).unwrap(), "\"something new\"");
+    }
+
+    #[test]
+    fn operation_type_to_json() {
+        let op_type = OperationType::AccountVerification;
+
+        assert_eq!(serde_json::to_string(&op_type).unwrap(), "\"account verification\"");
+    }
+}
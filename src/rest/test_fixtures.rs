@@ -0,0 +1,23 @@
+//! Callback shared by the tests of the signer and the webhook.
+
+use crate::rest::signer::MonetixSigner;
+
+pub const CALLBACK_JSON: &str = r#"
+{
+    "customer": {"id": "customer_id"},
+    "payment": {
+        "id": "payment_id",
+        "status": "success",
+        "type": "purchase",
+        "sum": {"amount": 1000, "currency": "USD"}
+    },
+    "project_id": 1000,
+    "signature": "SIGNATURE"
+}"#;
+
+/// Replaces the `SIGNATURE` placeholder with the signature of the json.
+pub fn signed_callback(signer: &MonetixSigner, json: &str) -> String {
+    let sign = signer.generate_sign_from_str(json).unwrap();
+
+    json.replace("SIGNATURE", &sign)
+}
@@ -0,0 +1,123 @@
+use crate::rest::errors::MonetixError;
+use async_trait::async_trait;
+use http::Method;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct MonetixHttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<String>,
+    /// Total timeout of the request, `None` to use the transport default
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MonetixHttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// Sends http requests for the rest clients. Implement it to run the clients
+/// against a mock or to add custom instrumentation.
+#[async_trait]
+pub trait MonetixTransport: Send + Sync {
+    async fn send(&self, request: MonetixHttpRequest) -> Result<MonetixHttpResponse, MonetixError>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl MonetixTransport for ReqwestTransport {
+    async fn send(&self, request: MonetixHttpRequest) -> Result<MonetixHttpResponse, MonetixError> {
+        let mut request_builder = self
+            .client
+            .request(request.method, &request.url)
+            .headers(request.headers);
+
+        if let Some(body) = request.body {
+            request_builder = request_builder.body(body);
+        }
+
+        if let Some(timeout) = request.timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
+
+        let response = request_builder.send().await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+
+        Ok(MonetixHttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// In-memory transport that records requests and answers with queued responses.
+///
+/// Panics if a request is sent when no responses are queued.
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    requests: Mutex<Vec<MonetixHttpRequest>>,
+    responses: Mutex<VecDeque<Result<MonetixHttpResponse, MonetixError>>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_response(&self, status: StatusCode, body: impl Into<String>) {
+        self.responses.lock().unwrap().push_back(Ok(MonetixHttpResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }));
+    }
+
+    /// Queues a failure to send the request, e.g. a connection reset.
+    pub fn push_transport_error(&self, message: &str, retryable: bool) {
+        self.responses
+            .lock()
+            .unwrap()
+            .push_back(Err(MonetixError::transport(message.to_string(), retryable)));
+    }
+
+    /// All requests sent so far, oldest first.
+    pub fn requests(&self) -> Vec<MonetixHttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl MonetixTransport for MockTransport {
+    async fn send(&self, request: MonetixHttpRequest) -> Result<MonetixHttpResponse, MonetixError> {
+        let url = request.url.clone();
+        self.requests.lock().unwrap().push(request);
+
+        let response = self.responses.lock().unwrap().pop_front();
+
+        match response {
+            Some(response) => response,
+            None => panic!("MockTransport: no response queued for {}", url),
+        }
+    }
+}
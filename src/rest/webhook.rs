@@ -0,0 +1,128 @@
+use crate::rest::callback::MonetixCallbackModel;
+use crate::rest::signer::{MonetixSigner, VerifyError};
+use async_trait::async_trait;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::Router;
+use std::sync::Arc;
+
+/// Receives callbacks that passed signature verification.
+#[async_trait]
+pub trait CallbackSink: Send + Sync + 'static {
+    /// Returning an error makes the handler answer with 500, so Monetix will resend the callback.
+    async fn handle(&self, callback: MonetixCallbackModel) -> Result<(), String>;
+}
+
+struct WebhookState<S: CallbackSink> {
+    signer: MonetixSigner,
+    sink: S,
+}
+
+/// Builds a router that accepts Monetix callbacks with `POST` on the given path.
+///
+/// Responses:
+/// 200 — callback is verified and handled by the sink;
+/// 400 — body is not a valid callback json;
+/// 401 — signature is missing or invalid;
+/// 500 — the sink failed to handle the callback.
+pub fn callback_router<S: CallbackSink>(path: &str, signer: MonetixSigner, sink: S) -> Router {
+    let state = Arc::new(WebhookState { signer, sink });
+
+    Router::new()
+        .route(path, post(handle_callback::<S>))
+        .with_state(state)
+}
+
+async fn handle_callback<S: CallbackSink>(
+    State(state): State<Arc<WebhookState<S>>>,
+    body: String,
+) -> (StatusCode, String) {
+    let callback = match state.signer.verify_callback(&body) {
+        Ok(callback) => callback,
+        Err(err @ (VerifyError::MissingSignature | VerifyError::InvalidSignature)) => {
+            return (StatusCode::UNAUTHORIZED, err.to_string());
+        }
+        Err(err @ (VerifyError::InvalidJson(_) | VerifyError::InvalidModel(_))) => {
+            return (StatusCode::BAD_REQUEST, err.to_string());
+        }
+    };
+
+    match state.sink.handle(callback).await {
+        Ok(()) => (StatusCode::OK, "OK".to_string()),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err),
+    }
+}
+
+/// Callback server for the tests of the webhook and of the clients that trigger callbacks.
+#[cfg(test)]
+pub(crate) mod test_server {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    struct ChannelSink(mpsc::UnboundedSender<MonetixCallbackModel>);
+
+    #[async_trait]
+    impl CallbackSink for ChannelSink {
+        async fn handle(&self, callback: MonetixCallbackModel) -> Result<(), String> {
+            self.0.send(callback).map_err(|err| err.to_string())
+        }
+    }
+
+    /// Serves `callback_router` on a free local port. Returns the callback url and the
+    /// receiver of the callbacks that passed verification.
+    pub(crate) async fn spawn_callback_server(
+        signer: MonetixSigner,
+    ) -> (String, mpsc::UnboundedReceiver<MonetixCallbackModel>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let router = callback_router("/callback", signer, ChannelSink(sender));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/callback", listener.local_addr().unwrap());
+
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest::signer::MonetixSigner;
+    use crate::rest::test_fixtures::{signed_callback, CALLBACK_JSON};
+    use crate::rest::webhook::test_server::spawn_callback_server;
+
+    async fn post(url: &str, body: String) -> reqwest::StatusCode {
+        reqwest::Client::new().post(url).body(body).send().await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn valid_callback() {
+        let signer = MonetixSigner::new("secret");
+        let body = signed_callback(&signer, CALLBACK_JSON);
+        let (url, mut receiver) = spawn_callback_server(signer).await;
+
+        let status = post(&url, body).await;
+
+        assert_eq!(status, reqwest::StatusCode::OK);
+        assert_eq!(receiver.recv().await.unwrap().payment.id, "payment_id");
+    }
+
+    #[tokio::test]
+    async fn invalid_signature() {
+        let (url, mut receiver) = spawn_callback_server(MonetixSigner::new("secret")).await;
+
+        let status = post(&url, CALLBACK_JSON.to_string()).await;
+
+        assert_eq!(status, reqwest::StatusCode::UNAUTHORIZED);
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn malformed_json() {
+        let (url, _receiver) = spawn_callback_server(MonetixSigner::new("secret")).await;
+
+        let status = post(&url, "{not a json".to_string()).await;
+
+        assert_eq!(status, reqwest::StatusCode::BAD_REQUEST);
+    }
+}